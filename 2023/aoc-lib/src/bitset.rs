@@ -0,0 +1,110 @@
+use std::{fmt::Display, hash::Hash};
+
+/// Dynamically sized set of small integers
+#[derive(Debug, Default, Clone)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            words: vec![0; capacity.div_ceil(64)],
+        }
+    }
+
+    pub fn contains(&self, i: usize) -> bool {
+        self.words
+            .get(i / 64)
+            .is_some_and(|word| word & (1 << (i % 64)) != 0)
+    }
+    /// Returns true if `i` was not already in the set
+    pub fn insert(&mut self, i: usize) -> bool {
+        if i / 64 >= self.words.len() {
+            self.words.resize(i / 64 + 1, 0);
+        }
+        let word = &mut self.words[i / 64];
+        let mask = 1 << (i % 64);
+        let inserted = *word & mask == 0;
+        *word |= mask;
+        inserted
+    }
+    /// Returns true if `i` was in the set
+    pub fn remove(&mut self, i: usize) -> bool {
+        let Some(word) = self.words.get_mut(i / 64) else {
+            return false;
+        };
+        let mask = 1 << (i % 64);
+        let removed = *word & mask != 0;
+        *word &= !mask;
+        removed
+    }
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+    // Words without the trailing empty ones, so that equality does not depend on capacity
+    fn trimmed(&self) -> &[u64] {
+        let len = self.words.len() - self.words.iter().rev().take_while(|&&w| w == 0).count();
+        &self.words[..len]
+    }
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(k, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+                let i = word.trailing_zeros() as usize;
+                word &= word - 1;
+                Some(k * 64 + i)
+            })
+        })
+    }
+}
+
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.trimmed() == other.trimmed()
+    }
+}
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.trimmed().hash(state)
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for i in iter {
+            set.insert(i);
+        }
+        set
+    }
+}
+
+impl Display for BitSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        let mut sep = "";
+        for i in self.iter() {
+            f.write_fmt(format_args!("{sep}{i}"))?;
+            sep = ",";
+        }
+        f.write_str("}")
+    }
+}
@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+
+use num::{PrimInt, Unsigned};
+
+/// Monotone priority queue for small integer keys (Dial's algorithm).
+///
+/// Keys pushed must never be lower than the last popped key.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<K, T> {
+    base: K,
+    len: usize,
+    buckets: VecDeque<Vec<T>>,
+}
+
+impl<K: PrimInt + Unsigned, T> Default for BucketQueue<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PrimInt + Unsigned, T> BucketQueue<K, T> {
+    pub fn new() -> Self {
+        Self {
+            base: K::zero(),
+            len: 0,
+            buckets: VecDeque::new(),
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            base: K::zero(),
+            len: 0,
+            buckets: VecDeque::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn clear(&mut self) {
+        self.base = K::zero();
+        self.len = 0;
+        self.buckets.clear();
+    }
+
+    pub fn push(&mut self, key: K, value: T) {
+        assert!(
+            key >= self.base,
+            "Pushed a key lower than the last popped one in a monotone queue"
+        );
+        let offset = (key - self.base)
+            .to_usize()
+            .filter(|&offset| offset < usize::MAX)
+            .expect("Key too far above the last popped one to get a bucket");
+        if offset >= self.buckets.len() {
+            self.buckets.resize_with(offset + 1, Vec::new);
+        }
+        self.buckets[offset].push(value);
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<(K, T)> {
+        // Scanning empty buckets would move the base past the last popped key
+        if self.is_empty() {
+            return None;
+        }
+        loop {
+            let bucket = self.buckets.front_mut()?;
+            if let Some(value) = bucket.pop() {
+                self.len -= 1;
+                return Some((self.base, value));
+            }
+            // Reuse the allocation of the exhausted bucket at the back
+            let bucket = self.buckets.pop_front().unwrap_or_default();
+            if !self.is_empty() {
+                self.buckets.push_back(bucket);
+            }
+            self.base = self.base + K::one();
+        }
+    }
+
+    pub fn peek(&self) -> Option<(K, &T)> {
+        let mut key = self.base;
+        for bucket in &self.buckets {
+            if let Some(value) = bucket.last() {
+                return Some((key, value));
+            }
+            key = key + K::one();
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_output() {
+        let mut queue = BucketQueue::<u32, usize>::new();
+        let mut popped = Vec::new();
+        for (i, key) in [5, 3, 17, 3, 0, 9].into_iter().enumerate() {
+            queue.push(key, i);
+        }
+        popped.push(queue.pop().unwrap().0);
+        popped.push(queue.pop().unwrap().0);
+        queue.push(4, 6);
+        queue.push(3, 7);
+        assert_eq!(queue.peek().map(|(key, _)| key), Some(3));
+        while let Some((key, _)) = queue.pop() {
+            popped.push(key);
+        }
+        assert_eq!(popped, vec![0, 3, 3, 3, 4, 5, 9, 17]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn empty_pop() {
+        let mut queue = BucketQueue::<u32, char>::new();
+        queue.push(2, 'a');
+        assert_eq!(queue.pop(), Some((2, 'a')));
+        assert_eq!(queue.pop(), None);
+        queue.push(2, 'b');
+        assert_eq!(queue.pop(), Some((2, 'b')));
+    }
+
+    #[test]
+    #[should_panic(expected = "Key too far above the last popped one")]
+    fn out_of_range_key() {
+        let mut queue = BucketQueue::<u128, ()>::new();
+        queue.push(u128::MAX, ());
+    }
+
+    #[test]
+    fn wraparound() {
+        // Only a few buckets are live, exhausted ones being recycled at the back
+        let mut queue = BucketQueue::<u8, u8>::new();
+        for key in 0..3 {
+            queue.push(key, key);
+        }
+        for expected in 0..=u8::MAX {
+            let (key, value) = queue.pop().unwrap();
+            assert_eq!((key, value), (expected, expected));
+            if let Some(next) = key.checked_add(3) {
+                queue.push(next, next);
+            }
+        }
+        assert!(queue.is_empty());
+        assert!(queue.buckets.len() <= 4);
+    }
+}
@@ -0,0 +1,184 @@
+use std::{convert::Infallible, hash::Hash};
+
+use hashbrown::HashMap;
+
+/// Eventually periodic sequence of states: `x[n + period] == x[n]` for all `n >= start`
+pub struct Cycle<T, F> {
+    /// Length of the prefix before entering the cycle (μ)
+    pub start: usize,
+    /// Length of the cycle (λ)
+    pub period: usize,
+    initial: T,
+    step: F,
+    // States from 0 to start + period (excluded), only kept by the memoized detection
+    states: Vec<T>,
+}
+
+impl<T, F> Cycle<T, F> {
+    /// Index of the earliest state equal to the `n`-th one
+    pub fn reduce(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.period
+        }
+    }
+}
+
+impl<T: Clone, F: FnMut(&T) -> T> Cycle<T, F> {
+    /// `n`-th state of the sequence, without stepping more than `start + period` times
+    pub fn nth_state(&mut self, n: usize) -> T {
+        let n = self.reduce(n);
+        if let Some(state) = self.states.get(n) {
+            return state.clone();
+        }
+        let mut state = self.initial.clone();
+        for _ in 0..n {
+            state = (self.step)(&state);
+        }
+        state
+    }
+}
+
+impl<T: Clone, E, F: FnMut(&T) -> Result<T, E>> Cycle<T, F> {
+    /// `n`-th state of a sequence with a fallible step, see [`Cycle::nth_state`]
+    pub fn try_nth_state(&mut self, n: usize) -> Result<T, E> {
+        let n = self.reduce(n);
+        if let Some(state) = self.states.get(n) {
+            return Ok(state.clone());
+        }
+        let mut state = self.initial.clone();
+        for _ in 0..n {
+            state = (self.step)(&state)?;
+        }
+        Ok(state)
+    }
+}
+
+/// Brent's cycle detection, keeping only a couple of states in memory
+pub fn find_cycle<T, F>(initial: T, mut step: F) -> Cycle<T, F>
+where
+    T: Clone + Eq,
+    F: FnMut(&T) -> T,
+{
+    // Find the period by comparing against the state at the last power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == period {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        period += 1;
+    }
+
+    // Find the start with two states `period` apart
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle {
+        start,
+        period,
+        initial,
+        step,
+        states: Vec::new(),
+    }
+}
+
+/// Cycle detection remembering every state, stepping only `start + period` times
+pub fn find_cycle_memo<T, F>(initial: T, mut step: F) -> Cycle<T, F>
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> T,
+{
+    let cycle = try_find_cycle_memo(initial, |state| Ok::<_, Infallible>(step(state)));
+    let Ok(Cycle {
+        start,
+        period,
+        initial,
+        states,
+        ..
+    }) = cycle;
+    Cycle {
+        start,
+        period,
+        initial,
+        step,
+        states,
+    }
+}
+
+/// [`find_cycle_memo`] with a fallible step, stopping at the first error
+pub fn try_find_cycle_memo<T, E, F>(initial: T, mut step: F) -> Result<Cycle<T, F>, E>
+where
+    T: Clone + Eq + Hash,
+    F: FnMut(&T) -> Result<T, E>,
+{
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial.clone();
+    let start = loop {
+        if let Some(&t) = seen.get(&state) {
+            break t;
+        }
+        seen.insert(state.clone(), states.len());
+        let next = step(&state)?;
+        states.push(state);
+        state = next;
+    };
+
+    Ok(Cycle {
+        start,
+        period: states.len() - start,
+        initial,
+        step,
+        states,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rho() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 3
+        let step = |&x: &u32| if x == 6 { 3 } else { x + 1 };
+        let mut brent = find_cycle(0, step);
+        let mut memo = find_cycle_memo(0, step);
+        for cycle in [&mut brent, &mut memo] {
+            assert_eq!((cycle.start, cycle.period), (3, 4));
+            assert_eq!(cycle.nth_state(2), 2);
+            assert_eq!(cycle.nth_state(7), 3);
+            assert_eq!(cycle.nth_state(1_000_000_000), 4);
+        }
+
+        let mut fixed = find_cycle(7, |&x: &u32| x);
+        assert_eq!((fixed.start, fixed.period), (0, 1));
+        assert_eq!(fixed.nth_state(10), 7);
+    }
+
+    #[test]
+    fn fallible() {
+        let step = |&x: &u32| if x < 5 { Ok(x + 1) } else { Err(x) };
+        assert_eq!(try_find_cycle_memo(0, step).err(), Some(5));
+
+        let step = |&x: &u32| Ok::<_, ()>((x + 1) % 3);
+        let mut cycle = try_find_cycle_memo(0, step).unwrap();
+        assert_eq!((cycle.start, cycle.period), (0, 3));
+        assert_eq!(cycle.try_nth_state(10), Ok(1));
+    }
+}
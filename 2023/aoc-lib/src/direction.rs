@@ -36,6 +36,8 @@ pub enum DirectionParseError {
     InvalidChar(AsciiChar),
     #[error("Could not convert string \"{0}\" into a direction because it is too long")]
     StringTooLong(AsciiString),
+    #[error("Could not convert string \"{0}\" into a diagonal direction")]
+    InvalidDiagonal(AsciiString),
 }
 
 impl Direction {
@@ -137,3 +139,195 @@ impl Direction {
         }
     }
 }
+
+/// Direction including the diagonals, in clockwise order every 45°
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, IntoPrimitive, TryFromPrimitive,
+)]
+#[repr(u8)]
+pub enum Direction8 {
+    East = 0,
+    SouthEast = 1,
+    South = 2,
+    SouthWest = 3,
+    West = 4,
+    NorthWest = 5,
+    North = 6,
+    NorthEast = 7,
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Direction8::East => "E",
+            Direction8::SouthEast => "SE",
+            Direction8::South => "S",
+            Direction8::SouthWest => "SW",
+            Direction8::West => "W",
+            Direction8::NorthWest => "NW",
+            Direction8::North => "N",
+            Direction8::NorthEast => "NE",
+        };
+        f.write_str(s)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Self::from_u8(dir as u8 * 2)
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    pub fn from_u8(value: u8) -> Self {
+        Self::try_from(value & 7).unwrap()
+    }
+
+    pub fn from_char(chr: AsciiChar) -> Result<Self, DirectionParseError> {
+        Direction::from_char(chr).map(Self::from)
+    }
+    /// Parses a single direction (`N`, `>`...) or a diagonal made of a vertical and an
+    /// horizontal one (`NE`, `SW`...)
+    pub fn from_ascii(s: &AsciiStr) -> Result<Self, DirectionParseError> {
+        match s.as_slice() {
+            [] => Err(DirectionParseError::EmptyString),
+            [chr] => Self::from_char(*chr),
+            [vertical, horizontal] => {
+                match (
+                    Direction::from_char(*vertical)?,
+                    Direction::from_char(*horizontal)?,
+                ) {
+                    (North, East) => Ok(Direction8::NorthEast),
+                    (North, West) => Ok(Direction8::NorthWest),
+                    (South, East) => Ok(Direction8::SouthEast),
+                    (South, West) => Ok(Direction8::SouthWest),
+                    _ => Err(DirectionParseError::InvalidDiagonal(s.to_owned())),
+                }
+            }
+            _ => Err(DirectionParseError::StringTooLong(s.to_owned())),
+        }
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as u8 & 1 == 1
+    }
+
+    /// Offset of one step, as `(row, column)`
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::East => (0, 1),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (1, 0),
+            Direction8::SouthWest => (1, -1),
+            Direction8::West => (0, -1),
+            Direction8::NorthWest => (-1, -1),
+            Direction8::North => (-1, 0),
+            Direction8::NorthEast => (-1, 1),
+        }
+    }
+    pub fn step(self, i: isize, j: isize) -> (isize, isize) {
+        let (di, dj) = self.delta();
+        (i + di, j + dj)
+    }
+    pub fn stepu(self, i: usize, j: usize) -> (usize, usize) {
+        let (di, dj) = self.delta();
+        (i.wrapping_add_signed(di), j.wrapping_add_signed(dj))
+    }
+
+    /// Rotates by 45°
+    pub fn rotate_clockwise(self) -> Self {
+        Self::from_u8(self as u8 + 1)
+    }
+    /// Rotates by 45°
+    pub fn rotate_counter_clockwise(self) -> Self {
+        Self::from_u8(self as u8 + 7)
+    }
+    pub fn rotate_left(self) -> Self {
+        self.rotate_counter_clockwise()
+    }
+    pub fn rotate_right(self) -> Self {
+        self.rotate_clockwise()
+    }
+
+    pub fn reflect(self) -> Self {
+        Self::from_u8(self as u8 ^ 4)
+    }
+
+    // Reflections map the angle `a` to `2 * axis - a`, counting in 45° clockwise from East
+    pub fn reflect_vertical(self) -> Self {
+        Self::from_u8(12 - self as u8)
+    }
+    pub fn reflect_horizontal(self) -> Self {
+        Self::from_u8(8 - self as u8)
+    }
+    pub fn reflect_diagonal_forward(self) -> Self {
+        Self::from_u8(14 - self as u8)
+    }
+    pub fn reflect_diagonal_backward(self) -> Self {
+        Self::from_u8(10 - self as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ascii::AsAsciiStr;
+
+    #[test]
+    fn direction8() {
+        for dir in Direction::ALL {
+            let dir8 = Direction8::from(dir);
+            assert_eq!(dir8.step(3, 5), dir.step(3, 5));
+            assert_eq!(dir8.reflect_vertical(), dir.reflect_vertical().into());
+            assert_eq!(dir8.reflect_horizontal(), dir.reflect_horizontal().into());
+            assert_eq!(
+                dir8.reflect_diagonal_forward(),
+                dir.reflect_diagonal_forward().into()
+            );
+            assert_eq!(
+                dir8.reflect_diagonal_backward(),
+                dir.reflect_diagonal_backward().into()
+            );
+            assert_eq!(
+                dir8.rotate_clockwise().rotate_clockwise(),
+                dir.rotate_clockwise().into()
+            );
+        }
+        assert_eq!(Direction8::North.rotate_right(), Direction8::NorthEast);
+        assert_eq!(Direction8::NorthEast.reflect(), Direction8::SouthWest);
+        assert_eq!(
+            Direction8::SouthEast.reflect_diagonal_forward(),
+            Direction8::NorthWest
+        );
+        assert_eq!(Direction8::NorthWest.step(0, 0), (-1, -1));
+
+        let parse = |s: &str| Direction8::from_ascii(s.as_ascii_str().unwrap());
+        assert_eq!(parse("NE").unwrap(), Direction8::NorthEast);
+        assert_eq!(parse("SW").unwrap(), Direction8::SouthWest);
+        assert_eq!(parse("<").unwrap(), Direction8::West);
+        assert!(matches!(
+            parse("NS"),
+            Err(DirectionParseError::InvalidDiagonal(_))
+        ));
+        assert!(matches!(
+            parse("NNE"),
+            Err(DirectionParseError::StringTooLong(_))
+        ));
+        assert_eq!(Direction8::SouthWest.to_string(), "SW");
+        assert_eq!(
+            Direction8::ALL.iter().filter(|d| d.is_diagonal()).count(),
+            4
+        );
+    }
+}
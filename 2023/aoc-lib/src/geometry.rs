@@ -0,0 +1,145 @@
+use crate::{gcd, Direction};
+
+/// Closed polygon with integer vertices given as `(row, column)`.
+///
+/// The last vertex is connected back to the first one.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon {
+    vertices: Vec<(i64, i64)>,
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<(i64, i64)>) -> Self {
+        Self { vertices }
+    }
+
+    /// Polygon traced from the origin by a stream of `(direction, length)` instructions
+    pub fn from_steps(steps: impl IntoIterator<Item = (Direction, i64)>) -> Self {
+        let mut builder = PolygonBuilder::new((0, 0));
+        for (dir, n) in steps {
+            builder.step(dir, n);
+        }
+        builder.build()
+    }
+
+    pub fn vertices(&self) -> &[(i64, i64)] {
+        &self.vertices
+    }
+
+    // Consecutive vertices, including the closing edge
+    fn edges(&self) -> impl Iterator<Item = ((i64, i64), (i64, i64))> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices.iter().copied().zip(next.copied())
+    }
+
+    /// Twice the signed area (shoelace formula), the sign depending on the orientation
+    pub fn signed_double_area(&self) -> i64 {
+        self.edges()
+            .map(|((i0, j0), (i1, j1))| j0 * i1 - i0 * j1)
+            .sum()
+    }
+    /// Twice the area, to stay exact for half-integer areas
+    pub fn double_area(&self) -> i64 {
+        self.signed_double_area().abs()
+    }
+
+    /// Number of lattice points on the edges
+    pub fn boundary_points(&self) -> i64 {
+        self.edges()
+            .map(|((i0, j0), (i1, j1))| gcd(i1 - i0, j1 - j0))
+            .sum()
+    }
+    /// Number of lattice points strictly inside, from Pick's theorem: A = I + B/2 - 1
+    pub fn interior_points(&self) -> i64 {
+        (self.double_area() - self.boundary_points() + 2) / 2
+    }
+    /// Number of lattice points inside or on the edges, i.e. the number of cells covered
+    /// when vertices are cell centers
+    pub fn lattice_points(&self) -> i64 {
+        self.interior_points() + self.boundary_points()
+    }
+}
+
+/// Builds a polygon by walking from a starting point
+#[derive(Debug, Clone)]
+pub struct PolygonBuilder {
+    position: (i64, i64),
+    vertices: Vec<(i64, i64)>,
+}
+
+impl PolygonBuilder {
+    pub fn new(start: (i64, i64)) -> Self {
+        Self {
+            position: start,
+            vertices: vec![start],
+        }
+    }
+
+    pub fn position(&self) -> (i64, i64) {
+        self.position
+    }
+
+    /// Walk `n` cells in direction `dir`
+    pub fn step(&mut self, dir: Direction, n: i64) -> &mut Self {
+        let (di, dj) = dir.step(0, 0);
+        let (i, j) = self.position;
+        self.line_to((i + di as i64 * n, j + dj as i64 * n))
+    }
+
+    /// Walk in a straight line to `position`
+    pub fn line_to(&mut self, position: (i64, i64)) -> &mut Self {
+        self.position = position;
+        self.vertices.push(position);
+        self
+    }
+
+    /// Close the polygon; walking back to the start is not required
+    pub fn build(mut self) -> Polygon {
+        if self.vertices.len() > 1 && self.vertices.last() == self.vertices.first() {
+            self.vertices.pop();
+        }
+        Polygon::new(self.vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Direction::*;
+
+    #[test]
+    fn dig_plan() {
+        let mut builder = PolygonBuilder::new((0, 0));
+        for (dir, n) in [(East, 6), (South, 5), (West, 6)] {
+            builder.step(dir, n);
+        }
+        let rectangle = builder.build();
+        assert_eq!(rectangle.double_area(), 60);
+        assert_eq!(rectangle.boundary_points(), 22);
+        assert_eq!(rectangle.interior_points(), 20);
+        assert_eq!(rectangle.lattice_points(), 42);
+
+        let triangle = Polygon::new(vec![(0, 0), (0, 4), (3, 0)]);
+        assert_eq!(triangle.double_area(), 12);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+
+        let lagoon = Polygon::from_steps([
+            (East, 6),
+            (South, 5),
+            (West, 2),
+            (South, 2),
+            (East, 2),
+            (South, 2),
+            (West, 5),
+            (North, 2),
+            (West, 1),
+            (North, 2),
+            (East, 2),
+            (North, 3),
+            (West, 2),
+            (North, 2),
+        ]);
+        assert_eq!(lagoon.lattice_points(), 62);
+    }
+}
@@ -0,0 +1,389 @@
+use std::hash::Hash;
+
+use ascii::{AsciiChar, AsciiStr, AsciiString};
+use thiserror::Error;
+
+use crate::{IntegerMap, SplitWhitespace};
+
+mod dominator;
+pub use dominator::DominatorTree;
+
+mod dot;
+pub use dot::{Attributes, Dot};
+
+mod flow;
+pub use flow::{FlowNetwork, StCut};
+
+mod longest_path;
+pub use longest_path::{longest_simple_path, LongestPathOptions};
+
+mod min_cut;
+pub use min_cut::{karger_stein, stoer_wagner, MinCut};
+
+mod traversal;
+pub use traversal::{
+    strongly_connected_components, topological_sort, Bfs, Dfs, DfsEvent, TopologicalSortError,
+};
+
+/// Graph whose nodes are identified by labels, interned into dense ids
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    nodes: IntegerMap<N, usize>,
+    adjacency: Vec<Vec<(usize, E)>>,
+    directed: bool,
+}
+
+/// Read access to adjacency lists of nodes identified by dense ids
+pub trait Adjacency {
+    type Weight;
+
+    fn node_count(&self) -> usize;
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &Self::Weight)> + '_;
+}
+
+impl<N, E> Adjacency for Graph<N, E> {
+    type Weight = E;
+
+    fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &E)> + '_ {
+        self.adjacency[node].iter().map(|(n, e)| (*n, e))
+    }
+}
+
+impl<E> Adjacency for [Vec<(usize, E)>] {
+    type Weight = E;
+
+    fn node_count(&self) -> usize {
+        self.len()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &E)> + '_ {
+        self[node].iter().map(|(n, e)| (*n, e))
+    }
+}
+
+impl Adjacency for [Vec<usize>] {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.len()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &())> + '_ {
+        self[node].iter().map(|n| (*n, &()))
+    }
+}
+
+impl<E> Adjacency for Vec<Vec<(usize, E)>> {
+    type Weight = E;
+
+    fn node_count(&self) -> usize {
+        self.as_slice().node_count()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &E)> + '_ {
+        self.as_slice().neighbors(node)
+    }
+}
+
+impl Adjacency for Vec<Vec<usize>> {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.as_slice().node_count()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &())> + '_ {
+        self.as_slice().neighbors(node)
+    }
+}
+
+impl<N, E> Graph<N, E> {
+    pub fn new_directed() -> Self {
+        Self {
+            nodes: IntegerMap::new(),
+            adjacency: Vec::new(),
+            directed: true,
+        }
+    }
+    pub fn new_undirected() -> Self {
+        Self {
+            nodes: IntegerMap::new(),
+            adjacency: Vec::new(),
+            directed: false,
+        }
+    }
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+    pub fn nodes(&self) -> std::ops::Range<usize> {
+        0..self.adjacency.len()
+    }
+    pub fn labels(&self) -> &[N] {
+        self.nodes.values()
+    }
+    pub fn adjacency(&self, node: usize) -> &[(usize, E)] {
+        &self.adjacency[node]
+    }
+    pub fn adjacency_lists(&self) -> &[Vec<(usize, E)>] {
+        &self.adjacency
+    }
+    pub fn into_adjacency_lists(self) -> Vec<Vec<(usize, E)>> {
+        self.adjacency
+    }
+    pub fn degree(&self, node: usize) -> usize {
+        self.adjacency[node].len()
+    }
+
+    /// Iterate over all edges, undirected edges being yielded only once
+    pub fn edges(&self) -> impl Iterator<Item = (usize, usize, &E)> + '_ {
+        self.adjacency
+            .iter()
+            .enumerate()
+            .flat_map(|(from, adj)| adj.iter().map(move |(to, e)| (from, *to, e)))
+            .filter(|(from, to, _)| self.directed || from <= to)
+    }
+    pub fn edge_count(&self) -> usize {
+        self.edges().count()
+    }
+}
+
+impl<N, E> Graph<N, E>
+where
+    N: Clone,
+    N: Hash,
+    N: Eq,
+{
+    pub fn add_node(&mut self, label: N) -> usize {
+        let id = self.nodes.id(label);
+        if id >= self.adjacency.len() {
+            self.adjacency.resize_with(id + 1, Vec::new);
+        }
+        id
+    }
+    pub fn node_id(&self, label: &N) -> Option<usize> {
+        self.nodes.get(label)
+    }
+    pub fn label(&self, node: usize) -> &N {
+        &self.nodes.values()[node]
+    }
+}
+
+impl<N, E> Graph<N, E>
+where
+    N: Clone,
+    N: Hash,
+    N: Eq,
+    E: Clone,
+{
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) -> (usize, usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.add_edge_by_id(from, to, weight);
+        (from, to)
+    }
+}
+
+impl<N, E: Clone> Graph<N, E> {
+    pub fn add_edge_by_id(&mut self, from: usize, to: usize, weight: E) {
+        if !self.directed && from != to {
+            self.adjacency[to].push((from, weight.clone()));
+        }
+        self.adjacency[from].push((to, weight));
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum GraphParseError {
+    #[error("Could not find any separator (':' or '->') in line \"{0}\"")]
+    MissingSeparator(AsciiString),
+    #[error("Empty node name in line \"{0}\"")]
+    EmptyName(AsciiString),
+}
+
+// Node type markers in front of names, like `%flip-flop -> a, b`
+const TYPE_PREFIXES: [AsciiChar; 2] = [AsciiChar::Percent, AsciiChar::Ampersand];
+
+/// Build a graph from lines like `name: a b c` or `name -> a, b`.
+///
+/// A `%` or `&` in front of a name is not part of the label, but is kept as the node prefix.
+#[derive(Debug, Clone)]
+pub struct GraphBuilder<'a, E = ()> {
+    graph: Graph<&'a AsciiStr, E>,
+    prefixes: Vec<Option<AsciiChar>>,
+}
+
+impl<'a, E: Clone + Default> GraphBuilder<'a, E> {
+    pub fn directed() -> Self {
+        Self {
+            graph: Graph::new_directed(),
+            prefixes: Vec::new(),
+        }
+    }
+    pub fn undirected() -> Self {
+        Self {
+            graph: Graph::new_undirected(),
+            prefixes: Vec::new(),
+        }
+    }
+
+    pub fn line(&mut self, line: &'a AsciiStr) -> Result<&mut Self, GraphParseError> {
+        let slice = line.as_slice();
+        let (name, targets) = if let Some(i) = slice
+            .windows(2)
+            .position(|w| w == [AsciiChar::Minus, AsciiChar::GreaterThan])
+        {
+            (&line[..i], &line[i + 2..])
+        } else if let Some(i) = slice.iter().position(|&chr| chr == AsciiChar::Colon) {
+            (&line[..i], &line[i + 1..])
+        } else {
+            return Err(GraphParseError::MissingSeparator(line.to_owned()));
+        };
+
+        let mut name = name.trim();
+        let prefix = name.first().filter(|chr| TYPE_PREFIXES.contains(chr));
+        if prefix.is_some() {
+            name = &name[1..];
+        }
+        if name.is_empty() {
+            return Err(GraphParseError::EmptyName(line.to_owned()));
+        }
+        let from = self.graph.add_node(name);
+        // Keep prefixes recorded by earlier definitions of the node
+        if prefix.is_some() {
+            if from >= self.prefixes.len() {
+                self.prefixes.resize(from + 1, None);
+            }
+            self.prefixes[from] = prefix;
+        }
+
+        for target in targets.split(AsciiChar::Comma) {
+            for target in target.split_whitespace() {
+                let to = self.graph.add_node(target);
+                self.graph.add_edge_by_id(from, to, E::default());
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Parse every non-empty line of `input`
+    pub fn lines(&mut self, input: &'a AsciiStr) -> Result<&mut Self, GraphParseError> {
+        for line in input.lines() {
+            if !line.trim().is_empty() {
+                self.line(line)?;
+            }
+        }
+        Ok(self)
+    }
+
+    /// Prefix of the node name where it was defined, if any
+    pub fn prefix(&self, node: usize) -> Option<AsciiChar> {
+        self.prefixes.get(node).copied().flatten()
+    }
+
+    pub fn build(self) -> Graph<&'a AsciiStr, E> {
+        self.graph
+    }
+    /// Also returns the prefix of every node
+    pub fn build_with_prefixes(mut self) -> (Graph<&'a AsciiStr, E>, Vec<Option<AsciiChar>>) {
+        self.prefixes.resize(self.graph.len(), None);
+        (self.graph, self.prefixes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ascii::AsAsciiStr;
+
+    use super::*;
+
+    #[test]
+    fn parse_round_trip() {
+        let input = "a -> b, c\nb -> c\nc -> a, d\n".as_ascii_str().unwrap();
+        let mut builder = GraphBuilder::<()>::directed();
+        builder.lines(input).unwrap();
+        let graph = builder.build();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edge_count(), 5);
+
+        let mut output = String::new();
+        for node in graph.nodes().filter(|&node| graph.degree(node) > 0) {
+            let targets = graph
+                .adjacency(node)
+                .iter()
+                .map(|&(to, _)| graph.label(to).as_str())
+                .collect::<Vec<_>>();
+            output += &format!("{} -> {}\n", graph.label(node), targets.join(", "));
+        }
+        assert_eq!(output, input.as_str());
+    }
+
+    #[test]
+    fn parse_prefixes() {
+        let input = "broadcaster -> a\n%a -> inv, con\n&inv -> b\n%b -> con\n&con -> output"
+            .as_ascii_str()
+            .unwrap();
+        let mut builder = GraphBuilder::<()>::directed();
+        builder.lines(input).unwrap();
+        let (graph, prefixes) = builder.build_with_prefixes();
+
+        let id = |name: &str| graph.node_id(&name.as_ascii_str().unwrap()).unwrap();
+        assert_eq!(graph.len(), 6);
+        assert_eq!(graph.adjacency(id("broadcaster")), &[(id("a"), ())]);
+        assert_eq!(prefixes[id("a")], Some(AsciiChar::Percent));
+        assert_eq!(prefixes[id("con")], Some(AsciiChar::Ampersand));
+        assert_eq!(prefixes[id("broadcaster")], None);
+        assert_eq!(prefixes[id("output")], None);
+
+        // Lines without prefix add edges without forgetting the prefix
+        let mut builder = GraphBuilder::<()>::directed();
+        builder
+            .lines("%a -> b\na -> c".as_ascii_str().unwrap())
+            .unwrap();
+        assert_eq!(builder.prefix(0), Some(AsciiChar::Percent));
+        let (graph, prefixes) = builder.build_with_prefixes();
+        assert_eq!(graph.degree(0), 2);
+        assert_eq!(prefixes, [Some(AsciiChar::Percent), None, None]);
+
+        let mut builder = GraphBuilder::<()>::directed();
+        let line = "% -> a".as_ascii_str().unwrap();
+        assert!(matches!(
+            builder.line(line),
+            Err(GraphParseError::EmptyName(_))
+        ));
+        let line = "a b".as_ascii_str().unwrap();
+        assert!(matches!(
+            builder.line(line),
+            Err(GraphParseError::MissingSeparator(_))
+        ));
+    }
+
+    #[test]
+    fn edges() {
+        let mut graph = Graph::new_undirected();
+        assert_eq!(graph.add_edge("a", "b", 1), (0, 1));
+        assert_eq!(graph.add_edge("b", "c", 2), (1, 2));
+        graph.add_edge("c", "c", 3);
+        assert_eq!(graph.degree(1), 2);
+        assert_eq!(graph.adjacency(2), &[(1, 2), (2, 3)]);
+        let edges = graph
+            .edges()
+            .map(|(a, b, &w)| (a, b, w))
+            .collect::<Vec<_>>();
+        assert_eq!(edges, vec![(0, 1, 1), (1, 2, 2), (2, 2, 3)]);
+
+        let mut graph = Graph::new_directed();
+        graph.add_edge('x', 'y', ());
+        graph.add_edge('y', 'x', ());
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.node_id(&'y'), Some(1));
+        assert_eq!(graph.node_id(&'z'), None);
+        assert_eq!(graph.labels(), &['x', 'y']);
+        assert_eq!(graph.neighbors(0).collect::<Vec<_>>(), vec![(1, &())]);
+    }
+}
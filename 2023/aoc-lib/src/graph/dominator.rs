@@ -0,0 +1,164 @@
+use super::{Adjacency, Dfs};
+
+/// Dominator tree of the nodes reachable from a root (Cooper-Harvey-Kennedy).
+///
+/// A node `a` dominates `b` if every path from the root to `b` goes through `a`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DominatorTree {
+    root: usize,
+    idom: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    // Pre-order interval of every node in the tree, to answer dominance queries in O(1)
+    enter: Vec<usize>,
+    exit: Vec<usize>,
+}
+
+impl DominatorTree {
+    pub fn new<G: Adjacency + ?Sized>(graph: &G, root: usize) -> Self {
+        let n = graph.node_count();
+        let postorder = Dfs::new(graph, root).postorder().collect::<Vec<_>>();
+        let mut rank = vec![usize::MAX; n];
+        for (k, &node) in postorder.iter().enumerate() {
+            rank[node] = k;
+        }
+
+        let mut predecessors = vec![Vec::new(); n];
+        for &node in &postorder {
+            for (next, _) in graph.neighbors(node) {
+                predecessors[next].push(node);
+            }
+        }
+
+        let mut idom = vec![None; n];
+        idom[root] = Some(root);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in postorder.iter().rev().skip(1) {
+                let mut new_idom = None;
+                for &pred in &predecessors[node] {
+                    if idom[pred].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => pred,
+                        Some(other) => intersect(&idom, &rank, pred, other),
+                    });
+                }
+                if new_idom != idom[node] {
+                    idom[node] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+        idom[root] = None;
+
+        let mut children = vec![Vec::new(); n];
+        for (node, &parent) in idom.iter().enumerate() {
+            if let Some(parent) = parent {
+                children[parent].push(node);
+            }
+        }
+
+        let mut tree = Self {
+            root,
+            idom,
+            children,
+            enter: vec![usize::MAX; n],
+            exit: vec![usize::MAX; n],
+        };
+        let mut time = 0;
+        let mut stack = vec![(root, false)];
+        while let Some((node, done)) = stack.pop() {
+            if done {
+                tree.exit[node] = time;
+                continue;
+            }
+            tree.enter[node] = time;
+            time += 1;
+            stack.push((node, true));
+            stack.extend(tree.children[node].iter().map(|&child| (child, false)));
+        }
+        tree
+    }
+
+    pub fn root(&self) -> usize {
+        self.root
+    }
+    /// Immediate dominator, `None` for the root and unreachable nodes
+    pub fn idom(&self, node: usize) -> Option<usize> {
+        self.idom[node]
+    }
+    pub fn children(&self, node: usize) -> &[usize] {
+        &self.children[node]
+    }
+    pub fn is_reachable(&self, node: usize) -> bool {
+        self.enter[node] != usize::MAX
+    }
+    /// Every node dominates itself
+    pub fn dominates(&self, a: usize, b: usize) -> bool {
+        self.is_reachable(a)
+            && self.is_reachable(b)
+            && self.enter[a] <= self.enter[b]
+            && self.exit[b] <= self.exit[a]
+    }
+    /// Number of nodes dominated by `node`, including itself
+    pub fn subtree_size(&self, node: usize) -> usize {
+        if self.is_reachable(node) {
+            self.exit[node] - self.enter[node]
+        } else {
+            0
+        }
+    }
+    /// Dominators of `node`, from itself up to the root
+    pub fn dominators(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let start = self.is_reachable(node).then_some(node);
+        std::iter::successors(start, |&node| self.idom[node])
+    }
+}
+
+// Common dominator of `a` and `b`, walking up the tree using post-order ranks
+fn intersect(idom: &[Option<usize>], rank: &[usize], mut a: usize, mut b: usize) -> usize {
+    while a != b {
+        while rank[a] < rank[b] {
+            a = idom[a].unwrap();
+        }
+        while rank[b] < rank[a] {
+            b = idom[b].unwrap();
+        }
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diamond() {
+        // 0 -> 1 -> {2, 3} -> 4 -> 5, and 6 unreachable
+        let graph: Vec<Vec<usize>> = vec![
+            vec![1],
+            vec![2, 3],
+            vec![4],
+            vec![4],
+            vec![5],
+            vec![],
+            vec![5],
+        ];
+        let tree = DominatorTree::new(&graph, 0);
+        let idom = (0..7).map(|node| tree.idom(node)).collect::<Vec<_>>();
+        assert_eq!(
+            idom,
+            vec![None, Some(0), Some(1), Some(1), Some(1), Some(4), None]
+        );
+        assert_eq!(tree.subtree_size(0), 6);
+        assert_eq!(tree.subtree_size(1), 5);
+        assert_eq!(tree.subtree_size(2), 1);
+        assert_eq!(tree.subtree_size(4), 2);
+        assert_eq!(tree.subtree_size(6), 0);
+        assert!(tree.dominates(1, 5));
+        assert!(!tree.dominates(2, 4));
+        assert_eq!(tree.dominators(5).collect::<Vec<_>>(), vec![5, 4, 1, 0]);
+    }
+}
@@ -0,0 +1,143 @@
+use std::{fmt::Display, io::Write, path::Path};
+
+use super::Adjacency;
+
+pub type Attributes = Vec<(&'static str, String)>;
+
+type NodeAttributes<'g> = Box<dyn Fn(usize) -> Attributes + 'g>;
+type EdgeAttributes<'g, W> = Box<dyn Fn(usize, usize, &W) -> Attributes + 'g>;
+
+/// Graphviz DOT writer for any adjacency lists
+pub struct Dot<'g, G: Adjacency + ?Sized> {
+    graph: &'g G,
+    name: String,
+    directed: bool,
+    graph_attributes: Attributes,
+    node_attributes: NodeAttributes<'g>,
+    edge_attributes: EdgeAttributes<'g, G::Weight>,
+}
+
+impl<'g, G: Adjacency + ?Sized> Dot<'g, G> {
+    pub fn directed(graph: &'g G) -> Self {
+        Self {
+            graph,
+            name: "G".to_string(),
+            directed: true,
+            graph_attributes: Vec::new(),
+            node_attributes: Box::new(|_| Vec::new()),
+            edge_attributes: Box::new(|_, _, _| Vec::new()),
+        }
+    }
+    /// Undirected graphs have symmetric adjacency lists: each edge is written only once
+    pub fn undirected(graph: &'g G) -> Self {
+        Self {
+            directed: false,
+            ..Self::directed(graph)
+        }
+    }
+
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+    pub fn graph_attribute(mut self, key: &'static str, value: impl ToString) -> Self {
+        self.graph_attributes.push((key, value.to_string()));
+        self
+    }
+    pub fn node_attributes(mut self, f: impl Fn(usize) -> Attributes + 'g) -> Self {
+        self.node_attributes = Box::new(f);
+        self
+    }
+    pub fn edge_attributes(
+        mut self,
+        f: impl Fn(usize, usize, &G::Weight) -> Attributes + 'g,
+    ) -> Self {
+        self.edge_attributes = Box::new(f);
+        self
+    }
+
+    pub fn write_to(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let mut file = std::fs::File::create(path)?;
+        write!(file, "{self}")
+    }
+}
+
+// Escapes a value to be written within double quotes
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn write_attributes(f: &mut std::fmt::Formatter<'_>, attributes: &Attributes) -> std::fmt::Result {
+    if attributes.is_empty() {
+        return Ok(());
+    }
+    f.write_str(" [")?;
+    let mut sep = "";
+    for (key, value) in attributes {
+        f.write_fmt(format_args!("{sep}{key}=\"{}\"", escape(value)))?;
+        sep = ", ";
+    }
+    f.write_str("]")
+}
+
+impl<G: Adjacency + ?Sized> Display for Dot<'_, G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (kind, arrow) = if self.directed {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        f.write_fmt(format_args!("{kind} \"{}\" {{\n", escape(&self.name)))?;
+        for (key, value) in &self.graph_attributes {
+            f.write_fmt(format_args!("  {key}=\"{}\";\n", escape(value)))?;
+        }
+
+        for node in 0..self.graph.node_count() {
+            f.write_fmt(format_args!("  {node}"))?;
+            write_attributes(f, &(self.node_attributes)(node))?;
+            f.write_str(";\n")?;
+        }
+
+        for node in 0..self.graph.node_count() {
+            for (next, weight) in self.graph.neighbors(node) {
+                if !self.directed && next < node {
+                    continue;
+                }
+                f.write_fmt(format_args!("  {node} {arrow} {next}"))?;
+                write_attributes(f, &(self.edge_attributes)(node, next, weight))?;
+                f.write_str(";\n")?;
+            }
+        }
+
+        f.write_str("}\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let adjacency = vec![vec![(1, 5)], vec![(0, 5), (2, 7)], vec![(1, 7)]];
+        let dot = Dot::undirected(&adjacency)
+            .name("say \"hi\"")
+            .graph_attribute("label", "a\\b")
+            .node_attributes(|node| vec![("label", format!("n{node}"))])
+            .edge_attributes(|_, _, &w| vec![("weight", w.to_string())]);
+        let expected = r#"graph "say \"hi\"" {
+  label="a\\b";
+  0 [label="n0"];
+  1 [label="n1"];
+  2 [label="n2"];
+  0 -- 1 [weight="5"];
+  1 -- 2 [weight="7"];
+}
+"#;
+        assert_eq!(dot.to_string(), expected);
+
+        let adjacency = vec![vec![1_usize], vec![]];
+        let dot = Dot::directed(&adjacency).to_string();
+        assert_eq!(dot, "digraph \"G\" {\n  0;\n  1;\n  0 -> 1;\n}\n");
+    }
+}
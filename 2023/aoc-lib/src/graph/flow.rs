@@ -0,0 +1,285 @@
+use std::collections::VecDeque;
+
+use super::Adjacency;
+
+/// Flow network with integer capacities.
+///
+/// Every edge is stored along with its reverse edge (`edge ^ 1`) holding the residual capacity.
+#[derive(Debug, Clone, Default)]
+pub struct FlowNetwork {
+    adjacency: Vec<Vec<usize>>,
+    from: Vec<usize>,
+    to: Vec<usize>,
+    capacity: Vec<u64>,
+    residual: Vec<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StCut {
+    pub weight: u64,
+    pub source_side: Vec<bool>,
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl FlowNetwork {
+    pub fn new(nodes: usize) -> Self {
+        Self {
+            adjacency: vec![Vec::new(); nodes],
+            ..Default::default()
+        }
+    }
+
+    /// Build a network from adjacency lists, every adjacency entry being a directed edge
+    pub fn from_adjacency<G: Adjacency + ?Sized>(
+        graph: &G,
+        mut capacity: impl FnMut(&G::Weight) -> u64,
+    ) -> Self {
+        let mut network = Self::new(graph.node_count());
+        for node in 0..graph.node_count() {
+            for (next, w) in graph.neighbors(node) {
+                network.add_edge(node, next, capacity(w));
+            }
+        }
+        network
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    fn push_edge(&mut self, from: usize, to: usize, capacity: u64) {
+        self.adjacency[from].push(self.to.len());
+        self.from.push(from);
+        self.to.push(to);
+        self.capacity.push(capacity);
+        self.residual.push(capacity);
+    }
+
+    /// Add a directed edge, returning its id
+    pub fn add_edge(&mut self, from: usize, to: usize, capacity: u64) -> usize {
+        let edge = self.to.len();
+        self.push_edge(from, to, capacity);
+        self.push_edge(to, from, 0);
+        edge
+    }
+
+    /// Add an edge usable in both directions, returning its id
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize, capacity: u64) -> usize {
+        let edge = self.to.len();
+        self.push_edge(a, b, capacity);
+        self.push_edge(b, a, capacity);
+        edge
+    }
+
+    pub fn endpoints(&self, edge: usize) -> (usize, usize) {
+        (self.from[edge], self.to[edge])
+    }
+    pub fn capacity(&self, edge: usize) -> u64 {
+        self.capacity[edge]
+    }
+    pub fn residual(&self, edge: usize) -> u64 {
+        self.residual[edge]
+    }
+    /// Net flow going through the edge, in its direction
+    pub fn flow(&self, edge: usize) -> i64 {
+        self.capacity[edge] as i64 - self.residual[edge] as i64
+    }
+
+    /// Edges leaving `node` that still have some residual capacity
+    pub fn residual_edges(&self, node: usize) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.adjacency[node]
+            .iter()
+            .map(|&edge| (self.to[edge], self.residual[edge]))
+            .filter(|&(_, residual)| residual > 0)
+    }
+
+    /// Remove any flow from the network
+    pub fn reset(&mut self) {
+        self.residual.copy_from_slice(&self.capacity);
+    }
+
+    fn augment(&mut self, edge: usize, amount: u64) {
+        self.residual[edge] -= amount;
+        self.residual[edge ^ 1] += amount;
+    }
+
+    // Distance from the source in the residual graph
+    fn levels(&self, source: usize) -> Vec<usize> {
+        let mut level = vec![usize::MAX; self.node_count()];
+        let mut queue = VecDeque::from([source]);
+        level[source] = 0;
+        while let Some(node) = queue.pop_front() {
+            for &edge in &self.adjacency[node] {
+                let next = self.to[edge];
+                if self.residual[edge] > 0 && level[next] == usize::MAX {
+                    level[next] = level[node] + 1;
+                    queue.push_back(next);
+                }
+            }
+        }
+        level
+    }
+
+    /// Edmonds-Karp max-flow, adding to the flow already in the network
+    pub fn edmonds_karp(&mut self, source: usize, sink: usize) -> u64 {
+        let mut total = 0;
+        let mut parent = vec![usize::MAX; self.node_count()];
+        loop {
+            parent.fill(usize::MAX);
+            let mut queue = VecDeque::from([source]);
+            while let Some(node) = queue.pop_front() {
+                if node == sink {
+                    break;
+                }
+                for &edge in &self.adjacency[node] {
+                    let next = self.to[edge];
+                    if self.residual[edge] > 0 && next != source && parent[next] == usize::MAX {
+                        parent[next] = edge;
+                        queue.push_back(next);
+                    }
+                }
+            }
+            if source == sink || parent[sink] == usize::MAX {
+                return total;
+            }
+
+            let mut amount = u64::MAX;
+            let mut node = sink;
+            while node != source {
+                let edge = parent[node];
+                amount = amount.min(self.residual[edge]);
+                node = self.from[edge];
+            }
+            let mut node = sink;
+            while node != source {
+                let edge = parent[node];
+                self.augment(edge, amount);
+                node = self.from[edge];
+            }
+            total += amount;
+        }
+    }
+
+    /// Dinic max-flow, adding to the flow already in the network
+    pub fn dinic(&mut self, source: usize, sink: usize) -> u64 {
+        let mut total = 0;
+        if source == sink {
+            return total;
+        }
+        loop {
+            let level = self.levels(source);
+            if level[sink] == usize::MAX {
+                return total;
+            }
+            let mut next_edge = vec![0_usize; self.node_count()];
+            loop {
+                let amount = self.blocking_path(source, sink, &level, &mut next_edge);
+                if amount == 0 {
+                    break;
+                }
+                total += amount;
+            }
+        }
+    }
+
+    // Find one augmenting path in the level graph and push as much flow as possible along it
+    fn blocking_path(
+        &mut self,
+        source: usize,
+        sink: usize,
+        level: &[usize],
+        next_edge: &mut [usize],
+    ) -> u64 {
+        let mut path = Vec::new();
+        let mut node = source;
+        loop {
+            if node == sink {
+                let amount = path
+                    .iter()
+                    .map(|&edge| self.residual[edge])
+                    .min()
+                    .unwrap_or(0);
+                for &edge in &path {
+                    self.augment(edge, amount);
+                }
+                return amount;
+            }
+
+            let mut advanced = false;
+            while let Some(&edge) = self.adjacency[node].get(next_edge[node]) {
+                let next = self.to[edge];
+                if self.residual[edge] > 0 && level[next] == level[node] + 1 {
+                    path.push(edge);
+                    node = next;
+                    advanced = true;
+                    break;
+                }
+                next_edge[node] += 1;
+            }
+
+            if !advanced {
+                // Dead end: retreat and never come back through this edge
+                let Some(edge) = path.pop() else {
+                    return 0;
+                };
+                node = self.from[edge];
+                next_edge[node] += 1;
+            }
+        }
+    }
+
+    /// Minimum s-t cut, given a maximum flow has already been pushed from `source`
+    pub fn min_cut(&self, source: usize) -> StCut {
+        let level = self.levels(source);
+        let source_side = level.iter().map(|&l| l != usize::MAX).collect::<Vec<_>>();
+        let mut cut = StCut {
+            weight: 0,
+            source_side,
+            edges: Vec::new(),
+        };
+        for edge in 0..self.to.len() {
+            let (from, to) = self.endpoints(edge);
+            if self.capacity[edge] > 0 && cut.source_side[from] && !cut.source_side[to] {
+                cut.weight += self.capacity[edge];
+                cut.edges.push((from, to));
+            }
+        }
+        cut
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network() -> FlowNetwork {
+        let mut network = FlowNetwork::new(6);
+        for (from, to, capacity) in [
+            (0, 1, 16),
+            (0, 2, 13),
+            (1, 3, 12),
+            (2, 1, 4),
+            (2, 4, 14),
+            (3, 2, 9),
+            (3, 5, 20),
+            (4, 3, 7),
+            (4, 5, 4),
+        ] {
+            network.add_edge(from, to, capacity);
+        }
+        network
+    }
+
+    #[test]
+    fn max_flow() {
+        let mut network = network();
+        assert_eq!(network.edmonds_karp(0, 5), 23);
+        assert_eq!(network.min_cut(0).weight, 23);
+
+        network.reset();
+        assert_eq!(network.dinic(0, 5), 23);
+        let cut = network.min_cut(0);
+        assert_eq!(cut.weight, 23);
+        assert_eq!(cut.edges, vec![(1, 3), (4, 3), (4, 5)]);
+    }
+}
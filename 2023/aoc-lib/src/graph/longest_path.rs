@@ -0,0 +1,242 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use hashbrown::HashMap;
+
+use super::Adjacency;
+use crate::BitSet;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LongestPathOptions {
+    /// Cache the longest remaining length for every (node, visited set) pair
+    pub memoize: bool,
+    /// Number of branching levels explored sequentially before spreading the subtrees on threads
+    pub parallel_depth: usize,
+}
+
+struct Solver {
+    adjacency: Vec<Vec<(usize, u64)>>,
+    // Heaviest edge entering every node: a path can gain at most that much by visiting it
+    best_in: Vec<u64>,
+    end: usize,
+    memoize: bool,
+    // Best length found so far plus one, 0 meaning that no path has been found yet
+    best: AtomicU64,
+}
+
+#[derive(Clone)]
+struct State {
+    node: usize,
+    length: u64,
+    remaining: u64,
+    visited: BitSet,
+}
+
+impl Solver {
+    fn record(&self, length: u64) {
+        self.best.fetch_max(length + 1, Ordering::Relaxed);
+    }
+
+    fn successors<'a>(&'a self, state: &'a State) -> impl Iterator<Item = State> + 'a {
+        self.adjacency[state.node]
+            .iter()
+            .filter(|(next, _)| !state.visited.contains(*next))
+            .map(|&(next, w)| {
+                let mut visited = state.visited.clone();
+                visited.insert(next);
+                State {
+                    node: next,
+                    length: state.length + w,
+                    remaining: state.remaining - self.best_in[next],
+                    visited,
+                }
+            })
+    }
+
+    // Depth-first search pruned by the upper bound of the remaining length
+    fn search(&self, node: usize, length: u64, remaining: u64, visited: &mut BitSet) {
+        if node == self.end {
+            self.record(length);
+            return;
+        }
+        let best = self.best.load(Ordering::Relaxed);
+        if best > 0 && length.saturating_add(remaining) < best {
+            return;
+        }
+        for &(next, w) in &self.adjacency[node] {
+            if visited.insert(next) {
+                self.search(next, length + w, remaining - self.best_in[next], visited);
+                visited.remove(next);
+            }
+        }
+    }
+
+    // Exact longest remaining length from `node`, without pruning so that it can be cached
+    fn remaining(
+        &self,
+        node: usize,
+        visited: &mut BitSet,
+        memo: &mut HashMap<(usize, BitSet), Option<u64>>,
+    ) -> Option<u64> {
+        if node == self.end {
+            return Some(0);
+        }
+        if let Some(&cached) = memo.get(&(node, visited.clone())) {
+            return cached;
+        }
+        let mut longest = None;
+        for &(next, w) in &self.adjacency[node] {
+            if visited.insert(next) {
+                if let Some(l) = self.remaining(next, visited, memo) {
+                    longest = longest.max(Some(l + w));
+                }
+                visited.remove(next);
+            }
+        }
+        memo.insert((node, visited.clone()), longest);
+        longest
+    }
+
+    fn solve_from(&self, mut state: State, memo: &mut HashMap<(usize, BitSet), Option<u64>>) {
+        if self.memoize {
+            if let Some(l) = self.remaining(state.node, &mut state.visited, memo) {
+                self.record(state.length + l);
+            }
+        } else {
+            self.search(
+                state.node,
+                state.length,
+                state.remaining,
+                &mut state.visited,
+            );
+        }
+    }
+}
+
+/// Length of the longest simple path from `start` to `end`, if any
+pub fn longest_simple_path<G: Adjacency + ?Sized>(
+    graph: &G,
+    mut weight: impl FnMut(&G::Weight) -> u64,
+    start: usize,
+    end: usize,
+    options: LongestPathOptions,
+) -> Option<u64> {
+    let n = graph.node_count();
+    let adjacency = (0..n)
+        .map(|node| {
+            graph
+                .neighbors(node)
+                .map(|(next, w)| (next, weight(w)))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut best_in = vec![0_u64; n];
+    for neighbors in &adjacency {
+        for &(next, w) in neighbors {
+            best_in[next] = best_in[next].max(w);
+        }
+    }
+
+    let mut visited = BitSet::with_capacity(n);
+    visited.insert(start);
+    let initial = State {
+        node: start,
+        length: 0,
+        // Saturating: a bound too large for u64 never prunes anything anyway
+        remaining: best_in
+            .iter()
+            .enumerate()
+            .filter(|&(node, _)| node != start)
+            .fold(0_u64, |sum, (_, &w)| sum.saturating_add(w)),
+        visited,
+    };
+    let solver = Solver {
+        adjacency,
+        best_in,
+        end,
+        memoize: options.memoize,
+        best: AtomicU64::new(0),
+    };
+
+    // Expand the first levels to get independent subtrees
+    let mut frontier = vec![initial];
+    for _ in 0..options.parallel_depth {
+        let mut next_frontier = Vec::new();
+        for state in &frontier {
+            if state.node == end {
+                solver.record(state.length);
+            } else {
+                next_frontier.extend(solver.successors(state));
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    if options.parallel_depth == 0 {
+        let mut memo = HashMap::new();
+        for state in frontier {
+            solver.solve_from(state, &mut memo);
+        }
+    } else {
+        let threads = thread::available_parallelism().map_or(1, |n| n.get());
+        let frontier = Mutex::new(frontier);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| {
+                    let mut memo = HashMap::new();
+                    // The lock is released before solving, within the closure
+                    let pop = || frontier.lock().ok().and_then(|mut f| f.pop());
+                    while let Some(state) = pop() {
+                        solver.solve_from(state, &mut memo);
+                    }
+                });
+            }
+        });
+    }
+
+    solver.best.load(Ordering::Relaxed).checked_sub(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_graph() {
+        // Undirected graph with a shortcut between 0 and 3
+        let graph: Vec<Vec<(usize, u64)>> = vec![
+            vec![(1, 2), (3, 10)],
+            vec![(0, 2), (2, 3), (4, 1)],
+            vec![(1, 3), (3, 4), (4, 7)],
+            vec![(0, 10), (2, 4)],
+            vec![(1, 1), (2, 7)],
+        ];
+        for (memoize, parallel_depth) in [(false, 0), (true, 0), (false, 2), (true, 1)] {
+            let options = LongestPathOptions {
+                memoize,
+                parallel_depth,
+            };
+            assert_eq!(longest_simple_path(&graph, |&w| w, 0, 4, options), Some(21));
+            assert_eq!(longest_simple_path(&graph, |&w| w, 0, 0, options), Some(0));
+        }
+    }
+
+    #[test]
+    fn huge_weights() {
+        // The bound on the remaining length exceeds u64, but the path itself fits
+        let graph: Vec<Vec<(usize, u64)>> = vec![
+            vec![(1, u64::MAX / 2), (2, u64::MAX / 2)],
+            vec![(0, u64::MAX / 2), (3, 1)],
+            vec![(0, u64::MAX / 2), (3, 2)],
+            vec![(1, 1), (2, 2)],
+        ];
+        let options = LongestPathOptions::default();
+        let longest = longest_simple_path(&graph, |&w| w, 0, 3, options);
+        assert_eq!(longest, Some(u64::MAX / 2 + 2));
+    }
+}
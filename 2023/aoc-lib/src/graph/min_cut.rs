@@ -0,0 +1,315 @@
+use std::collections::BinaryHeap;
+
+use hashbrown::HashMap;
+
+use super::Adjacency;
+use crate::UnionFind;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MinCut {
+    pub weight: u64,
+    pub edges: Vec<(usize, usize)>,
+    pub partition: [Vec<usize>; 2],
+}
+
+impl MinCut {
+    // Build the cut from the side of every node, considering adjacency lists as undirected
+    fn from_sides<G: Adjacency + ?Sized>(
+        graph: &G,
+        weight: &mut impl FnMut(&G::Weight) -> u64,
+        side: &[bool],
+    ) -> Self {
+        let mut cut = MinCut {
+            weight: 0,
+            edges: Vec::new(),
+            partition: [Vec::new(), Vec::new()],
+        };
+        for node in 0..graph.node_count() {
+            cut.partition[side[node] as usize].push(node);
+            for (next, w) in graph.neighbors(node) {
+                if !side[node] && side[next] {
+                    cut.weight += weight(w);
+                    cut.edges.push((node, next));
+                } else if side[node] && !side[next] {
+                    cut.weight += weight(w);
+                    cut.edges.push((next, node));
+                }
+            }
+        }
+        // Edges are listed in both directions in undirected adjacency lists
+        cut.edges.sort_unstable();
+        cut.edges.dedup();
+        cut.weight /= 2;
+        cut
+    }
+}
+
+/// Stoer-Wagner deterministic global minimum cut.
+///
+/// Adjacency lists must be symmetric (undirected graph), with non-negative weights.
+pub fn stoer_wagner<G: Adjacency + ?Sized>(
+    graph: &G,
+    mut weight: impl FnMut(&G::Weight) -> u64,
+) -> Option<MinCut> {
+    let n = graph.node_count();
+    if n < 2 {
+        return None;
+    }
+
+    let mut adjacency = (0..n)
+        .map(|node| {
+            let mut adj = HashMap::<usize, u64>::new();
+            for (next, w) in graph.neighbors(node) {
+                if next != node {
+                    *adj.entry(next).or_default() += weight(w);
+                }
+            }
+            adj
+        })
+        .collect::<Vec<_>>();
+    let mut groups = (0..n).map(|node| vec![node]).collect::<Vec<_>>();
+    let mut active = (0..n).collect::<Vec<_>>();
+
+    let mut best_weight = u64::MAX;
+    let mut best_group = Vec::new();
+
+    let mut connectivity = vec![0_u64; n];
+    let mut added = vec![false; n];
+    let mut heap = BinaryHeap::new();
+
+    while active.len() > 1 {
+        // Maximum adjacency ordering
+        for &node in &active {
+            connectivity[node] = 0;
+            added[node] = false;
+        }
+        heap.clear();
+        heap.push((0, active[0]));
+        let mut order = Vec::with_capacity(active.len());
+        while let Some((w, node)) = heap.pop() {
+            if added[node] || w != connectivity[node] {
+                continue;
+            }
+            added[node] = true;
+            order.push(node);
+            for (&next, &w) in &adjacency[node] {
+                if !added[next] {
+                    connectivity[next] += w;
+                    heap.push((connectivity[next], next));
+                }
+            }
+        }
+        // Disconnected graph: nodes never reached form a zero-weight cut
+        if order.len() < active.len() {
+            best_group = order
+                .iter()
+                .flat_map(|&node| groups[node].clone())
+                .collect();
+            break;
+        }
+
+        let t = order[order.len() - 1];
+        let s = order[order.len() - 2];
+        if connectivity[t] < best_weight {
+            best_weight = connectivity[t];
+            best_group = groups[t].clone();
+        }
+
+        // Merge t into s
+        let t_adjacency = std::mem::take(&mut adjacency[t]);
+        for (next, w) in t_adjacency {
+            adjacency[next].remove(&t);
+            if next != s {
+                *adjacency[s].entry(next).or_default() += w;
+                *adjacency[next].entry(s).or_default() += w;
+            }
+        }
+        let t_group = std::mem::take(&mut groups[t]);
+        groups[s].extend(t_group);
+        active.retain(|&node| node != t);
+    }
+
+    let mut side = vec![false; n];
+    for node in best_group {
+        side[node] = true;
+    }
+    Some(MinCut::from_sides(graph, &mut weight, &side))
+}
+
+// SplitMix64, good enough to shuffle edges reproducibly
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+}
+
+struct Contracted {
+    nodes: usize,
+    edges: Vec<(usize, usize, u64)>,
+}
+
+impl Contracted {
+    // Randomly contract edges, picked proportionally to their weight, until `target` nodes remain.
+    // Also returns the new label of every node.
+    fn contract(&self, target: usize, rng: &mut Rng) -> (Vec<usize>, Self) {
+        let mut keyed = self
+            .edges
+            .iter()
+            .map(|&(a, b, w)| (-(1.0 - rng.next_f64()).ln() / w as f64, a, b))
+            .collect::<Vec<_>>();
+        keyed.sort_unstable_by(|x, y| x.0.total_cmp(&y.0));
+
+        let mut union_find = UnionFind::<usize, usize>::with_capacity(self.nodes);
+        for _ in 0..self.nodes {
+            union_find.push(1);
+        }
+        let mut nodes = self.nodes;
+        for (_, a, b) in keyed {
+            if nodes <= target {
+                break;
+            }
+            if union_find.root(a) != union_find.root(b) {
+                union_find.merge(a, b);
+                nodes -= 1;
+            }
+        }
+
+        let mut relabel = vec![usize::MAX; self.nodes];
+        let mut count = 0;
+        for node in 0..self.nodes {
+            let root = union_find.root(node);
+            if relabel[root] == usize::MAX {
+                relabel[root] = count;
+                count += 1;
+            }
+            relabel[node] = relabel[root];
+        }
+
+        // Merge parallel edges to keep the contracted graphs small
+        let mut edges = self
+            .edges
+            .iter()
+            .map(|&(a, b, w)| (relabel[a].min(relabel[b]), relabel[a].max(relabel[b]), w))
+            .filter(|(a, b, _)| a != b)
+            .collect::<Vec<_>>();
+        edges.sort_unstable_by_key(|&(a, b, _)| (a, b));
+        edges.dedup_by(|(a, b, w), (a0, b0, w0)| {
+            let parallel = a == a0 && b == b0;
+            if parallel {
+                *w0 += *w;
+            }
+            parallel
+        });
+
+        let contracted = Self {
+            nodes: count,
+            edges,
+        };
+        (relabel, contracted)
+    }
+
+    // Weight of the cut found, and the side of every node
+    fn karger_stein(&self, rng: &mut Rng) -> (u64, Vec<bool>) {
+        if self.nodes <= 6 {
+            let (relabel, contracted) = self.contract(2, rng);
+            let weight = contracted.edges.iter().map(|(_, _, w)| w).sum();
+            return (weight, relabel.into_iter().map(|l| l != 0).collect());
+        }
+
+        let target = 1 + (self.nodes as f64 / std::f64::consts::SQRT_2).ceil() as usize;
+        let mut best = (u64::MAX, Vec::new());
+        for _ in 0..2 {
+            let (relabel, contracted) = self.contract(target, rng);
+            let (weight, side) = contracted.karger_stein(rng);
+            if weight < best.0 {
+                best = (weight, relabel.into_iter().map(|l| side[l]).collect());
+            }
+        }
+        best
+    }
+}
+
+/// Karger-Stein randomized global minimum cut, keeping the best cut out of `trials` runs.
+///
+/// Adjacency lists must be symmetric (undirected graph), with positive weights.
+/// Results are reproducible for a given `seed`.
+pub fn karger_stein<G: Adjacency + ?Sized>(
+    graph: &G,
+    mut weight: impl FnMut(&G::Weight) -> u64,
+    seed: u64,
+    trials: usize,
+) -> Option<MinCut> {
+    let n = graph.node_count();
+    if n < 2 {
+        return None;
+    }
+
+    let mut edges = Vec::new();
+    for node in 0..n {
+        for (next, w) in graph.neighbors(node) {
+            if node < next {
+                edges.push((node, next, weight(w)));
+            }
+        }
+    }
+    let initial = Contracted { nodes: n, edges };
+
+    let mut rng = Rng(seed);
+    let mut best = (u64::MAX, Vec::new());
+    for _ in 0..trials.max(1) {
+        let candidate = initial.karger_stein(&mut rng);
+        if candidate.0 < best.0 {
+            best = candidate;
+        }
+    }
+
+    let side = best.1;
+    Some(MinCut::from_sides(graph, &mut weight, &side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::GraphBuilder;
+    use ascii::AsAsciiStr;
+
+    const EXAMPLE: &str = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr
+";
+
+    #[test]
+    fn example() {
+        let mut builder = GraphBuilder::<()>::undirected();
+        builder.lines(EXAMPLE.as_ascii_str().unwrap()).unwrap();
+        let graph = builder.build();
+
+        for cut in [
+            stoer_wagner(&graph, |_| 1).unwrap(),
+            karger_stein(&graph, |_| 1, 42, 10).unwrap(),
+        ] {
+            assert_eq!(cut.weight, 3);
+            assert_eq!(cut.edges.len(), 3);
+            assert_eq!(cut.partition[0].len() * cut.partition[1].len(), 54);
+        }
+    }
+}
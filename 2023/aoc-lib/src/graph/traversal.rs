@@ -0,0 +1,259 @@
+use std::collections::VecDeque;
+
+use thiserror::Error;
+
+use super::Adjacency;
+
+/// Breadth-first iterator yielding nodes with their distance (in edges) to the start
+pub struct Bfs<'g, G: ?Sized> {
+    graph: &'g G,
+    seen: Vec<bool>,
+    queue: VecDeque<(usize, usize)>,
+}
+
+impl<'g, G: Adjacency + ?Sized> Bfs<'g, G> {
+    pub fn new(graph: &'g G, start: usize) -> Self {
+        let mut seen = vec![false; graph.node_count()];
+        seen[start] = true;
+        Self {
+            graph,
+            seen,
+            queue: VecDeque::from([(start, 0)]),
+        }
+    }
+}
+
+impl<G: Adjacency + ?Sized> Iterator for Bfs<'_, G> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, depth) = self.queue.pop_front()?;
+        for (next, _) in self.graph.neighbors(node) {
+            if !self.seen[next] {
+                self.seen[next] = true;
+                self.queue.push_back((next, depth + 1));
+            }
+        }
+        Some((node, depth))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DfsEvent {
+    Pre(usize),
+    Post(usize),
+}
+
+/// Depth-first iterator yielding both pre-order and post-order events
+pub struct Dfs<'g, G: ?Sized> {
+    graph: &'g G,
+    seen: Vec<bool>,
+    stack: Vec<(usize, bool)>,
+}
+
+impl<'g, G: Adjacency + ?Sized> Dfs<'g, G> {
+    pub fn new(graph: &'g G, start: usize) -> Self {
+        Self {
+            graph,
+            seen: vec![false; graph.node_count()],
+            stack: vec![(start, false)],
+        }
+    }
+    pub fn preorder(self) -> impl Iterator<Item = usize> + 'g {
+        self.filter_map(|event| match event {
+            DfsEvent::Pre(node) => Some(node),
+            DfsEvent::Post(_) => None,
+        })
+    }
+    pub fn postorder(self) -> impl Iterator<Item = usize> + 'g {
+        self.filter_map(|event| match event {
+            DfsEvent::Pre(_) => None,
+            DfsEvent::Post(node) => Some(node),
+        })
+    }
+}
+
+impl<G: Adjacency + ?Sized> Iterator for Dfs<'_, G> {
+    type Item = DfsEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, expanded) = self.stack.pop()?;
+            if expanded {
+                return Some(DfsEvent::Post(node));
+            }
+            if self.seen[node] {
+                continue;
+            }
+            self.seen[node] = true;
+            self.stack.push((node, true));
+
+            let first = self.stack.len();
+            self.stack.extend(
+                self.graph
+                    .neighbors(node)
+                    .filter(|(next, _)| !self.seen[*next])
+                    .map(|(next, _)| (next, false)),
+            );
+            // Neighbors are visited in adjacency order
+            self.stack[first..].reverse();
+
+            return Some(DfsEvent::Pre(node));
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TopologicalSortError {
+    #[error("Graph contains a cycle: {0:?}")]
+    Cycle(Vec<usize>),
+}
+
+/// Kahn's topological sort, reporting one of the cycles if there is any
+pub fn topological_sort<G: Adjacency + ?Sized>(
+    graph: &G,
+) -> Result<Vec<usize>, TopologicalSortError> {
+    let n = graph.node_count();
+    let mut in_degree = vec![0_usize; n];
+    for node in 0..n {
+        for (next, _) in graph.neighbors(node) {
+            in_degree[next] += 1;
+        }
+    }
+
+    let mut order = Vec::with_capacity(n);
+    let mut queue = (0..n)
+        .filter(|&node| in_degree[node] == 0)
+        .collect::<VecDeque<_>>();
+    while let Some(node) = queue.pop_front() {
+        order.push(node);
+        for (next, _) in graph.neighbors(node) {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() == n {
+        return Ok(order);
+    }
+
+    // Every remaining node has a remaining predecessor: walk them backward until looping
+    let mut predecessor = vec![usize::MAX; n];
+    for node in 0..n {
+        for (next, _) in graph.neighbors(node) {
+            if in_degree[node] > 0 && in_degree[next] > 0 {
+                predecessor[next] = node;
+            }
+        }
+    }
+    let mut position = vec![usize::MAX; n];
+    let mut walk = Vec::new();
+    let mut node = (0..n).find(|&node| in_degree[node] > 0).unwrap_or_default();
+    while position[node] == usize::MAX {
+        position[node] = walk.len();
+        walk.push(node);
+        node = predecessor[node];
+    }
+    let mut cycle = walk.split_off(position[node]);
+    cycle.reverse();
+    cycle.rotate_right(1);
+
+    Err(TopologicalSortError::Cycle(cycle))
+}
+
+/// Tarjan's strongly connected components, in reverse topological order
+pub fn strongly_connected_components<G: Adjacency + ?Sized>(graph: &G) -> Vec<Vec<usize>> {
+    let n = graph.node_count();
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0_usize; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut counter = 0;
+
+    // Neighbors are collected once to iterate over them without recursion
+    let neighbors = (0..n)
+        .map(|node| {
+            graph
+                .neighbors(node)
+                .map(|(next, _)| next)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+        let mut call_stack = vec![(root, 0_usize)];
+        index[root] = counter;
+        lowlink[root] = counter;
+        counter += 1;
+        stack.push(root);
+        on_stack[root] = true;
+
+        while let Some(&mut (node, ref mut i)) = call_stack.last_mut() {
+            if let Some(&next) = neighbors[node].get(*i) {
+                *i += 1;
+                if index[next] == usize::MAX {
+                    index[next] = counter;
+                    lowlink[next] = counter;
+                    counter += 1;
+                    stack.push(next);
+                    on_stack[next] = true;
+                    call_stack.push((next, 0));
+                } else if on_stack[next] {
+                    lowlink[node] = lowlink[node].min(index[next]);
+                }
+                continue;
+            }
+
+            call_stack.pop();
+            if let Some(&(parent, _)) = call_stack.last() {
+                lowlink[parent] = lowlink[parent].min(lowlink[node]);
+            }
+            if lowlink[node] == index[node] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn topological_sort_cycle() {
+        let dag: Vec<Vec<usize>> = vec![vec![1, 2], vec![3], vec![3], vec![]];
+        assert_eq!(topological_sort(&dag).unwrap(), vec![0, 1, 2, 3]);
+
+        let cyclic: Vec<Vec<usize>> = vec![vec![1], vec![2], vec![3, 1], vec![]];
+        let Err(TopologicalSortError::Cycle(cycle)) = topological_sort(&cyclic) else {
+            panic!("cycle not detected");
+        };
+        assert_eq!(cycle, vec![1, 2]);
+    }
+
+    #[test]
+    fn scc() {
+        let graph: Vec<Vec<usize>> = vec![vec![1], vec![2], vec![0, 3], vec![4], vec![3]];
+        let mut components = strongly_connected_components(&graph);
+        for component in &mut components {
+            component.sort();
+        }
+        assert_eq!(components, vec![vec![3, 4], vec![0, 1, 2]]);
+    }
+}
@@ -1,11 +1,14 @@
 use std::{
+    collections::VecDeque,
     fmt::Display,
     hash::Hash,
-    ops::{Index, IndexMut},
+    ops::{AddAssign, Index, IndexMut},
 };
 
 use thiserror::Error;
 
+use crate::{Direction, IntegerMap, UnionFind};
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
     nrows: usize,
@@ -183,6 +186,394 @@ impl<T: Clone> Grid<T> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    // Neighbors already visited by a row-major scan
+    fn causal_neighbors(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1)],
+            Connectivity::Eight => &[(-1, -1), (-1, 0), (-1, 1), (0, -1)],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ComponentFeatures {
+    pub area: usize,
+    pub perimeter: usize,
+    pub min: (isize, isize),
+    pub max: (isize, isize),
+}
+
+impl Default for ComponentFeatures {
+    fn default() -> Self {
+        Self {
+            area: 0,
+            perimeter: 0,
+            min: (isize::MAX, isize::MAX),
+            max: (isize::MIN, isize::MIN),
+        }
+    }
+}
+
+impl AddAssign for ComponentFeatures {
+    fn add_assign(&mut self, rhs: Self) {
+        self.area += rhs.area;
+        self.perimeter += rhs.perimeter;
+        self.min = (self.min.0.min(rhs.min.0), self.min.1.min(rhs.min.1));
+        self.max = (self.max.0.max(rhs.max.0), self.max.1.max(rhs.max.1));
+    }
+}
+
+impl<T> Grid<T> {
+    /// Label the connected components of the grid, two adjacent cells being connected
+    /// if `same_component` returns true for them.
+    ///
+    /// Labels in the returned grid are roots of the returned `UnionFind`,
+    /// which holds the features of each component.
+    pub fn label_components(
+        &self,
+        connectivity: Connectivity,
+        mut same_component: impl FnMut(&T, &T) -> bool,
+    ) -> (Grid<u32>, UnionFind<u32, ComponentFeatures>) {
+        let mut labels = Grid::with_size(self.nrows, self.ncols, 0_u32);
+        let mut union_find = UnionFind::with_capacity(self.data.len());
+
+        for i in 0..self.nrows as isize {
+            for j in 0..self.ncols as isize {
+                let cell = &self.data[i as usize * self.ncols + j as usize];
+                let mut label = None;
+
+                for &(di, dj) in connectivity.causal_neighbors() {
+                    let (ni, nj) = (i + di, j + dj);
+                    let Some(neighbor) = self.get(ni, nj) else {
+                        continue;
+                    };
+                    if same_component(neighbor, cell) {
+                        let l = labels[ni as usize][nj as usize];
+                        label = Some(match label {
+                            Some(label) => union_find.merge(label, l),
+                            None => union_find.root(l),
+                        });
+                    }
+                }
+
+                let mut perimeter = 0;
+                for (di, dj) in [(-1, 0), (0, -1), (0, 1), (1, 0)] {
+                    match self.get(i + di, j + dj) {
+                        Some(neighbor) if same_component(cell, neighbor) => (),
+                        _ => perimeter += 1,
+                    }
+                }
+                let features = ComponentFeatures {
+                    area: 1,
+                    perimeter,
+                    min: (i, j),
+                    max: (i, j),
+                };
+
+                let label = match label {
+                    Some(label) => {
+                        *union_find.features_mut(label) += features;
+                        label
+                    }
+                    None => union_find
+                        .push(features)
+                        .expect("Too many components for u32 labels"),
+                };
+                labels[i as usize][j as usize] = label;
+            }
+        }
+
+        for label in labels.data.iter_mut() {
+            *label = union_find.root(*label);
+        }
+
+        (labels, union_find)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Mark all the cells reachable from `start` through passable cells
+    pub fn flood_fill(
+        &self,
+        start: (isize, isize),
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Grid<bool> {
+        let mut filled = Grid::with_size(self.nrows, self.ncols, false);
+        let mut stack = Vec::new();
+
+        if self.get(start.0, start.1).is_some_and(&mut passable) {
+            filled[start.0 as usize][start.1 as usize] = true;
+            stack.push(start);
+        }
+
+        while let Some((i, j)) = stack.pop() {
+            for (di, dj) in [(-1, 0), (0, -1), (0, 1), (1, 0)] {
+                let (i, j) = (i + di, j + dj);
+                let Some(cell) = self.get(i, j) else {
+                    continue;
+                };
+                let seen = &mut filled[i as usize][j as usize];
+                if !*seen && passable(cell) {
+                    *seen = true;
+                    stack.push((i, j));
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Compute the distance to the nearest source for every reachable cell
+    pub fn bfs_distances(
+        &self,
+        sources: impl IntoIterator<Item = (isize, isize)>,
+        mut passable: impl FnMut(&T) -> bool,
+    ) -> Grid<Option<u32>> {
+        let mut distances = Grid::with_size(self.nrows, self.ncols, None);
+        let mut queue = VecDeque::new();
+
+        for (i, j) in sources {
+            if self.get(i, j).is_some_and(&mut passable) {
+                let distance = &mut distances[i as usize][j as usize];
+                if distance.is_none() {
+                    *distance = Some(0);
+                    queue.push_back((i, j, 0));
+                }
+            }
+        }
+
+        while let Some((i, j, d)) = queue.pop_front() {
+            for (di, dj) in [(-1, 0), (0, -1), (0, 1), (1, 0)] {
+                let (i, j) = (i + di, j + dj);
+                let Some(cell) = self.get(i, j) else {
+                    continue;
+                };
+                let distance = &mut distances[i as usize][j as usize];
+                if distance.is_none() && passable(cell) {
+                    *distance = Some(d + 1);
+                    queue.push_back((i, j, d + 1));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Mark the cells reachable from the sources in exactly `steps` steps.
+    ///
+    /// As steps can go back and forth, those are the cells reached by a walk no longer
+    /// than `steps` and with the same parity, as long as the walk can be extended.
+    pub fn reachable_in_exactly(
+        &self,
+        sources: impl IntoIterator<Item = (isize, isize)>,
+        passable: impl FnMut(&T) -> bool,
+        steps: u32,
+    ) -> Grid<bool> {
+        let passable = self.map(passable);
+        let is_passable = |i: isize, j: isize| passable.get(i, j).is_some_and(|&p| p);
+        let neighbors = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+        // Shortest walk of each parity to every cell
+        let mut distances = Grid::with_size(self.nrows, self.ncols, [None; 2]);
+        let mut queue = VecDeque::new();
+        for (i, j) in sources {
+            if is_passable(i, j) {
+                let distance = &mut distances[i as usize][j as usize][0];
+                if distance.is_none() {
+                    *distance = Some(0);
+                    queue.push_back((i, j, 0));
+                }
+            }
+        }
+
+        while let Some((i, j, d)) = queue.pop_front() {
+            let parity = (d as usize + 1) % 2;
+            for (di, dj) in neighbors {
+                let (i, j) = (i + di, j + dj);
+                if !is_passable(i, j) {
+                    continue;
+                }
+                let distance = &mut distances[i as usize][j as usize][parity];
+                if distance.is_none() {
+                    *distance = Some(d + 1);
+                    queue.push_back((i, j, d + 1));
+                }
+            }
+        }
+
+        let mut reachable = Grid::with_size(self.nrows, self.ncols, false);
+        for i in 0..self.nrows as isize {
+            for j in 0..self.ncols as isize {
+                let Some(d) = distances[i as usize][j as usize][steps as usize % 2] else {
+                    continue;
+                };
+                // Going back and forth needs a neighbor, which a source might not have
+                let can_wait = d > 0
+                    || neighbors
+                        .iter()
+                        .any(|&(di, dj)| is_passable(i + di, j + dj));
+                reachable[i as usize][j as usize] = d == steps || (d < steps && can_wait);
+            }
+        }
+        reachable
+    }
+}
+
+type Junctions = IntegerMap<(isize, isize), usize>;
+
+impl<T> Grid<T> {
+    /// Contract the grid into a graph whose nodes are junctions, and edges are corridors.
+    ///
+    /// Junctions are cells connected to at least 3 neighbors, or cells for which `is_node` is true.
+    /// `passable(from, to, dir)` tells if one can step from the cell `from` to its neighbor `to`
+    /// in the direction `dir`; corridors going against a one-way step are discarded.
+    /// Edges are weighted by the length of their corridor.
+    pub fn contract_to_graph(
+        &self,
+        passable: impl Fn(&T, &T, Direction) -> bool,
+        mut is_node: impl FnMut((isize, isize), &T) -> bool,
+    ) -> (Junctions, Vec<Vec<(usize, u32)>>) {
+        // Neighbors connected in at least one direction
+        let connected = |i: isize, j: isize| {
+            let cell = &self.data[i as usize * self.ncols + j as usize];
+            let mut dirs = [None; 4];
+            for (k, dir) in Direction::ALL.into_iter().enumerate() {
+                let (ni, nj) = dir.step(i, j);
+                if let Some(neighbor) = self.get(ni, nj) {
+                    if passable(cell, neighbor, dir) || passable(neighbor, cell, dir.reflect()) {
+                        dirs[k] = Some(dir);
+                    }
+                }
+            }
+            dirs
+        };
+
+        let mut nodes = IntegerMap::new();
+        for i in 0..self.nrows as isize {
+            for j in 0..self.ncols as isize {
+                let cell = &self.data[i as usize * self.ncols + j as usize];
+                let degree = connected(i, j).iter().flatten().count();
+                if degree >= 3 || is_node((i, j), cell) {
+                    nodes.id((i, j));
+                }
+            }
+        }
+
+        let mut adjacency = vec![Vec::new(); nodes.len()];
+        for (id, &(i, j)) in nodes.values().iter().enumerate() {
+            for dir in connected(i, j).into_iter().flatten() {
+                let (mut pi, mut pj) = (i, j);
+                let mut d = dir;
+                let mut length = 0;
+                loop {
+                    let (ni, nj) = d.step(pi, pj);
+                    let (Some(from), Some(to)) = (self.get(pi, pj), self.get(ni, nj)) else {
+                        break;
+                    };
+                    if !passable(from, to, d) {
+                        break;
+                    }
+                    length += 1;
+                    if let Some(n) = nodes.get(&(ni, nj)) {
+                        adjacency[id].push((n, length));
+                        break;
+                    }
+                    // Inside a corridor: continue to the other neighbor, if any
+                    let Some(next) = connected(ni, nj)
+                        .into_iter()
+                        .flatten()
+                        .find(|&next| next != d.reflect())
+                    else {
+                        break;
+                    };
+                    (pi, pj, d) = (ni, nj, next);
+                }
+            }
+        }
+
+        (nodes, adjacency)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Set the cells of the segment from `from` to `to` (Bresenham), skipping those outside the grid
+    pub fn draw_line(&mut self, from: (isize, isize), to: (isize, isize), val: T) {
+        let (mut i, mut j) = from;
+        let di = -(to.0 - i).abs();
+        let dj = (to.1 - j).abs();
+        let (si, sj) = ((to.0 - i).signum(), (to.1 - j).signum());
+        let mut err = dj + di;
+        loop {
+            if let Some(cell) = self.get_mut(i, j) {
+                *cell = val.clone();
+            }
+            if (i, j) == to {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= di {
+                err += di;
+                j += sj;
+            }
+            if e2 <= dj {
+                err += dj;
+                i += si;
+            }
+        }
+    }
+
+    /// Draw segments between consecutive points
+    pub fn draw_polyline(&mut self, points: &[(isize, isize)], val: T) {
+        for segment in points.windows(2) {
+            self.draw_line(segment[0], segment[1], val.clone());
+        }
+    }
+}
+
+impl Grid<bool> {
+    /// Cells inside or on the boundary of a closed polygon whose vertices are cell centers.
+    ///
+    /// The interior is found by even-odd scanlines, only counting the edges going up from each
+    /// row, so that corners (`L`, `J`, `7`, `F`) are handled like on a pipe loop.
+    pub fn fill_polygon(nrows: usize, ncols: usize, vertices: &[(isize, isize)]) -> Self {
+        let mut grid = Self::with_size(nrows, ncols, false);
+        if vertices.is_empty() {
+            return grid;
+        }
+        let closing = [vertices[vertices.len() - 1], vertices[0]];
+        grid.draw_polyline(vertices, true);
+        grid.draw_polyline(&closing, true);
+
+        let edges = vertices.iter().zip(vertices.iter().cycle().skip(1));
+        let mut starts = Vec::new();
+        for i in 0..nrows as isize {
+            // First column on the right of every edge crossing
+            starts.clear();
+            for (&a, &b) in edges.clone() {
+                let ((i0, j0), (i1, j1)) = if a.0 < b.0 { (a, b) } else { (b, a) };
+                if i0 < i && i <= i1 {
+                    let num = j0 * (i1 - i0) + (i - i0) * (j1 - j0);
+                    starts.push(num.div_euclid(i1 - i0) + 1);
+                }
+            }
+            starts.sort_unstable();
+            for span in starts.chunks_exact(2) {
+                for j in span[0].max(0)..span[1].min(ncols as isize) {
+                    grid[i as usize][j as usize] = true;
+                }
+            }
+        }
+        grid
+    }
+}
+
 impl<T> Default for Grid<T> {
     #[inline]
     fn default() -> Self {
@@ -258,3 +649,176 @@ impl<'a, T> Iterator for GridIteratorMut<'a, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(rows: &[&str]) -> Grid<u8> {
+        let mut grid = Grid::new();
+        for row in rows {
+            grid.add_row(row.bytes()).unwrap();
+        }
+        grid
+    }
+
+    #[test]
+    fn components() {
+        let grid = parse(&["#..#", ".#.#", "..##"]);
+
+        let (labels, union_find) = grid.label_components(Connectivity::Four, |a, b| a == b);
+        let count = |labels: &Grid<u32>| {
+            let mut roots = labels.iter().flatten().collect::<Vec<_>>();
+            roots.sort();
+            roots.dedup();
+            roots.len()
+        };
+        assert_eq!(count(&labels), 5);
+        assert_ne!(labels[0][0], labels[1][1]);
+        let features = union_find.features(labels[0][3]);
+        assert_eq!(features.area, 4);
+        assert_eq!(features.perimeter, 10);
+        assert_eq!((features.min, features.max), ((0, 2), (2, 3)));
+
+        let (labels, _) = grid.label_components(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(count(&labels), 2);
+        assert_eq!(labels[0][0], labels[2][2]);
+    }
+
+    #[test]
+    fn fill_and_distances() {
+        let grid = parse(&["..#.", ".##.", "...#"]);
+        let filled = grid.flood_fill((0, 0), |&c| c == b'.');
+        assert_eq!(filled.iter().flatten().filter(|&&f| f).count(), 6);
+        assert!(!filled[0][3]);
+        assert!(!grid.flood_fill((0, 2), |&c| c == b'.')[0][2]);
+
+        let distances = grid.bfs_distances([(0, 0), (0, 3)], |&c| c == b'.');
+        assert_eq!(distances[2][2], Some(4));
+        assert_eq!(distances[1][3], Some(1));
+        assert_eq!(distances[1][1], None);
+    }
+
+    #[test]
+    fn reachable() {
+        let open = |&c: &u8| c != b'#';
+        let line = parse(&["..."]);
+        let reachable = line.reachable_in_exactly([(0, 0), (0, 1)], open, 2);
+        assert_eq!(reachable[0], [true, true, true]);
+
+        let single = parse(&["."]);
+        assert!(!single.reachable_in_exactly([(0, 0)], open, 2)[0][0]);
+        assert!(single.reachable_in_exactly([(0, 0)], open, 0)[0][0]);
+
+        let garden = parse(&[
+            "...........",
+            ".....###.#.",
+            ".###.##..#.",
+            "..#.#...#..",
+            "....#.#....",
+            ".##..S####.",
+            ".##..#...#.",
+            ".......##..",
+            ".##.#.####.",
+            ".##..##.##.",
+            "...........",
+        ]);
+        let reachable = garden.reachable_in_exactly([(5, 5)], open, 6);
+        assert_eq!(reachable.iter().flatten().filter(|&&r| r).count(), 16);
+    }
+
+    #[test]
+    fn corridors() {
+        use crate::graph::{longest_simple_path, LongestPathOptions};
+
+        let grid = parse(&[
+            "#.#####################",
+            "#.......#########...###",
+            "#######.#########.#.###",
+            "###.....#.>.>.###.#.###",
+            "###v#####.#v#.###.#.###",
+            "###.>...#.#.#.....#...#",
+            "###v###.#.#.#########.#",
+            "###...#.#.#.......#...#",
+            "#####.#.#.#######.#.###",
+            "#.....#.#.#.......#...#",
+            "#.#####.#.#.#########v#",
+            "#.#...#...#...###...>.#",
+            "#.#.#v#######v###.###v#",
+            "#...#.>.#...>.>.#.###.#",
+            "#####v#.#.###v#.#.###.#",
+            "#.....#...#...#.#.#...#",
+            "#.#########.###.#.#.###",
+            "#...###...#...#...#.###",
+            "###.###.#.###v#####v###",
+            "#...#...#.#.>.>.#.>.###",
+            "#.###.###.#.###.#.#v###",
+            "#.....###...###...#...#",
+            "#####################.#",
+        ]);
+        let (start, end) = ((0, 1), (22, 21));
+        let longest = |slopes: bool| {
+            let (nodes, adjacency) = grid.contract_to_graph(
+                |&from, &to, dir| {
+                    let slope = match from {
+                        b'>' => Some(Direction::East),
+                        b'v' => Some(Direction::South),
+                        b'<' => Some(Direction::West),
+                        b'^' => Some(Direction::North),
+                        _ => None,
+                    };
+                    let downhill = slope.is_none_or(|slope| slope == dir);
+                    from != b'#' && to != b'#' && (!slopes || downhill)
+                },
+                |pos, _| pos == start || pos == end,
+            );
+            let (start, end) = (nodes.get(&start).unwrap(), nodes.get(&end).unwrap());
+            longest_simple_path(
+                &adjacency,
+                |&l| l as u64,
+                start,
+                end,
+                LongestPathOptions::default(),
+            )
+        };
+        assert_eq!(longest(true), Some(94));
+        assert_eq!(longest(false), Some(154));
+    }
+
+    #[test]
+    fn rasterize() {
+        let mut grid = Grid::with_size(4, 5, '.');
+        grid.draw_line((0, 0), (3, 4), '#');
+        grid.draw_line((3, 0), (3, 9), '-');
+        let rows = grid
+            .iter()
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(rows, ["#....", ".#...", "..##.", "-----"]);
+
+        // U-shaped loop: the notch between both arms stays outside
+        let vertices = [
+            (0, 0),
+            (0, 2),
+            (2, 2),
+            (2, 4),
+            (0, 4),
+            (0, 6),
+            (4, 6),
+            (4, 0),
+        ];
+        let filled = Grid::fill_polygon(5, 7, &vertices);
+        let rows = filled
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|&c| if c { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            ["###.###", "###.###", "#######", "#######", "#######"]
+        );
+    }
+}
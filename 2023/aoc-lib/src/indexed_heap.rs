@@ -0,0 +1,192 @@
+use num::ToPrimitive;
+
+const ABSENT: usize = usize::MAX;
+
+/// Binary min-heap whose elements are identified by a dense integer id,
+/// allowing to decrease the key of an element already in the heap.
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<I, K> {
+    heap: Vec<(K, I)>,
+    positions: Vec<usize>,
+}
+
+impl<I, K> Default for IndexedHeap<I, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, K> IndexedHeap<I, K> {
+    pub fn new() -> Self {
+        Self {
+            heap: Vec::new(),
+            positions: Vec::new(),
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            heap: Vec::with_capacity(capacity),
+            positions: Vec::with_capacity(capacity),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    pub fn clear(&mut self) {
+        self.heap.clear();
+        self.positions.clear();
+    }
+}
+
+impl<I, K> IndexedHeap<I, K>
+where
+    I: Copy,
+    I: ToPrimitive,
+    K: Ord,
+{
+    fn position(&self, id: I) -> usize {
+        id.to_usize()
+            .and_then(|id| self.positions.get(id).copied())
+            .unwrap_or(ABSENT)
+    }
+
+    pub fn contains(&self, id: I) -> bool {
+        self.position(id) != ABSENT
+    }
+
+    pub fn key(&self, id: I) -> Option<&K> {
+        self.heap.get(self.position(id)).map(|(key, _)| key)
+    }
+
+    pub fn peek(&self) -> Option<(I, &K)> {
+        self.heap.first().map(|(key, id)| (*id, key))
+    }
+
+    /// Insert `id` with `key`, or decrease its key if it is already in the heap.
+    ///
+    /// Returns false if `id` was already in the heap with a key no greater than `key`.
+    pub fn push_or_decrease(&mut self, id: I, key: K) -> bool {
+        let pos = self.position(id);
+        if pos == ABSENT {
+            let idx = id.to_usize().expect("Heap id is not a valid index");
+            if idx >= self.positions.len() {
+                self.positions.resize(idx + 1, ABSENT);
+            }
+            self.heap.push((key, id));
+            self.positions[idx] = self.heap.len() - 1;
+            self.sift_up(self.heap.len() - 1);
+            true
+        } else if key < self.heap[pos].0 {
+            self.heap[pos].0 = key;
+            self.sift_up(pos);
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn pop_min(&mut self) -> Option<(I, K)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (key, id) = self.heap.pop()?;
+        self.set_position(id, ABSENT);
+        self.sift_down(0);
+        Some((id, key))
+    }
+
+    fn set_position(&mut self, id: I, pos: usize) {
+        if let Some(idx) = id.to_usize() {
+            self.positions[idx] = pos;
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        self.set_position(self.heap[a].1, a);
+        self.set_position(self.heap[b].1, b);
+    }
+
+    fn sift_up(&mut self, mut pos: usize) {
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if self.heap[pos].0 >= self.heap[parent].0 {
+                break;
+            }
+            self.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut pos: usize) {
+        loop {
+            let left = 2 * pos + 1;
+            let right = left + 1;
+            let mut smallest = pos;
+            if left < self.heap.len() && self.heap[left].0 < self.heap[smallest].0 {
+                smallest = left;
+            }
+            if right < self.heap.len() && self.heap[right].0 < self.heap[smallest].0 {
+                smallest = right;
+            }
+            if smallest == pos {
+                break;
+            }
+            self.swap(pos, smallest);
+            pos = smallest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_output() {
+        let mut heap = IndexedHeap::<usize, u32>::new();
+        for (id, key) in [5, 3, 17, 0, 1024, 9].into_iter().enumerate() {
+            assert!(heap.push_or_decrease(id, key));
+        }
+        assert_eq!(heap.pop_min(), Some((3, 0)));
+        assert!(!heap.contains(3));
+        heap.push_or_decrease(3, 4);
+        let mut popped = Vec::new();
+        while let Some((id, key)) = heap.pop_min() {
+            popped.push((id, key));
+        }
+        assert_eq!(
+            popped,
+            vec![(1, 3), (3, 4), (0, 5), (5, 9), (2, 17), (4, 1024)]
+        );
+        assert!(heap.is_empty());
+    }
+
+    #[test]
+    fn decrease_key() {
+        let mut heap = IndexedHeap::<u32, u32>::new();
+        heap.push_or_decrease(7, 10);
+        heap.push_or_decrease(2, 20);
+        heap.push_or_decrease(4, 30);
+        assert!(heap.contains(4));
+        assert!(!heap.contains(5));
+        assert!(!heap.contains(100));
+
+        assert!(heap.push_or_decrease(4, 5));
+        assert!(!heap.push_or_decrease(2, 25));
+        assert_eq!(heap.key(4), Some(&5));
+        assert_eq!(heap.key(2), Some(&20));
+        assert_eq!(heap.len(), 3);
+        assert_eq!(heap.peek(), Some((4, &5)));
+
+        assert_eq!(heap.pop_min(), Some((4, 5)));
+        assert_eq!(heap.pop_min(), Some((7, 10)));
+        assert_eq!(heap.pop_min(), Some((2, 20)));
+        assert_eq!(heap.pop_min(), None);
+    }
+}
@@ -33,6 +33,15 @@ impl<T, I> IntegerMap<T, I> {
         self.value2int.clear();
         self.int2value.clear();
     }
+    pub fn len(&self) -> usize {
+        self.int2value.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.int2value.is_empty()
+    }
+    pub fn values(&self) -> &[T] {
+        &self.int2value
+    }
 }
 
 impl<T, I> IntegerMap<T, I>
@@ -60,8 +69,30 @@ where
     pub fn id(&mut self, value: T) -> I {
         self.try_insert(value).0
     }
+    pub fn get(&self, value: &T) -> Option<I> {
+        self.value2int.get(value).cloned()
+    }
 
     pub fn value(&self, id: I) -> T {
         self.int2value[id.to_usize().unwrap_or(usize::MAX)].clone()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interning() {
+        let mut map = IntegerMap::<&str, u8>::new();
+        assert!(map.is_empty());
+        assert_eq!(map.try_insert("a"), (0, true));
+        assert_eq!(map.id("b"), 1);
+        assert_eq!(map.try_insert("a"), (0, false));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.values(), &["a", "b"]);
+        assert_eq!(map.get(&"b"), Some(1));
+        assert_eq!(map.get(&"c"), None);
+        assert_eq!(map.value(1), "b");
+    }
+}
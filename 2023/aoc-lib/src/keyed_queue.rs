@@ -0,0 +1,112 @@
+use num::{PrimInt, Unsigned};
+
+use crate::{BucketQueue, RadixHeap};
+
+/// Priority queue popping the values in increasing key order
+pub trait MonotoneQueue {
+    type Key;
+    type Value;
+
+    fn push(&mut self, key: Self::Key, value: Self::Value);
+    fn pop(&mut self) -> Option<(Self::Key, Self::Value)>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<K: PrimInt + Unsigned, T> MonotoneQueue for BucketQueue<K, T> {
+    type Key = K;
+    type Value = T;
+
+    fn push(&mut self, key: K, value: T) {
+        BucketQueue::push(self, key, value)
+    }
+    fn pop(&mut self) -> Option<(K, T)> {
+        BucketQueue::pop(self)
+    }
+    fn len(&self) -> usize {
+        BucketQueue::len(self)
+    }
+}
+
+impl<K: PrimInt + Unsigned, T> MonotoneQueue for RadixHeap<K, T> {
+    type Key = K;
+    type Value = T;
+
+    fn push(&mut self, key: K, value: T) {
+        RadixHeap::push(self, key, value)
+    }
+    fn pop(&mut self) -> Option<(K, T)> {
+        RadixHeap::pop(self)
+    }
+    fn len(&self) -> usize {
+        RadixHeap::len(self)
+    }
+}
+
+/// `BinaryHeap`-like front end of a monotone queue, the key of an item being given by `key`.
+///
+/// Unlike `BinaryHeap`, the smallest item is popped first.
+#[derive(Debug, Clone)]
+pub struct KeyedQueue<Q, F> {
+    queue: Q,
+    key: F,
+}
+
+impl<Q, F> KeyedQueue<Q, F>
+where
+    Q: MonotoneQueue,
+    F: Fn(&Q::Value) -> Q::Key,
+{
+    pub fn new(queue: Q, key: F) -> Self {
+        Self { queue, key }
+    }
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+    pub fn push(&mut self, item: Q::Value) {
+        let key = (self.key)(&item);
+        self.queue.push(key, item);
+    }
+    pub fn pop(&mut self) -> Option<Q::Value> {
+        self.queue.pop().map(|(_, item)| item)
+    }
+    pub fn into_inner(self) -> Q {
+        self.queue
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lengths<Q, F>(mut queue: KeyedQueue<Q, F>) -> Vec<usize>
+    where
+        Q: MonotoneQueue<Value = &'static str>,
+        F: Fn(&&'static str) -> Q::Key,
+    {
+        std::iter::from_fn(|| queue.pop().map(str::len)).collect()
+    }
+
+    #[test]
+    fn items() {
+        let words = ["ccc", "a", "bb", "dddd", "ee"];
+        let mut bucket = KeyedQueue::new(BucketQueue::new(), |s: &&str| s.len());
+        let mut radix = KeyedQueue::new(RadixHeap::new(), |s: &&str| s.len() as u32);
+        for word in words {
+            bucket.push(word);
+            radix.push(word);
+        }
+        assert_eq!(bucket.len(), 5);
+        assert_eq!(bucket.pop(), Some("a"));
+        assert_eq!(radix.pop(), Some("a"));
+        bucket.push("f");
+        radix.push("f");
+        assert_eq!(lengths(bucket), [1, 2, 2, 3, 4]);
+        assert_eq!(lengths(radix), [1, 2, 2, 3, 4]);
+    }
+}
@@ -1,11 +1,19 @@
 mod grid;
-pub use grid::Grid;
+pub use grid::{ComponentFeatures, Connectivity, Grid};
 
 mod factor_expand;
 pub use factor_expand::{Expand, Factor};
 
 mod math;
-pub use math::{abs_diff, gcd, lcm};
+pub use math::{
+    abs_diff, align_cycles, checked_gcd, checked_lcm, count_integer_solutions_quadratic, crt,
+    extended_gcd, gcd, icbrt, isqrt, lcm, lcm_all, mod_inverse, pow_mod, quadratic_negative_range,
+    quadratic_non_negative_range, quadratic_non_positive_range, quadratic_positive_range,
+    CycleHits, QuadraticError,
+};
+
+mod mod_int;
+pub use mod_int::{DynModInt, ModInt};
 
 mod char_ext;
 pub use char_ext::CharExt;
@@ -30,7 +38,35 @@ mod int_map;
 pub use int_map::IntegerMap;
 
 mod direction;
-pub use direction::Direction;
+pub use direction::{Direction, Direction8};
+
+mod vector;
+pub use vector::{Intersection, Line2, Vec2, Vec3};
+
+mod walker;
+pub use walker::Walker;
+
+mod bitset;
+pub use bitset::BitSet;
+
+mod bucket_queue;
+pub use bucket_queue::BucketQueue;
+
+mod radix_heap;
+pub use radix_heap::RadixHeap;
+
+mod keyed_queue;
+pub use keyed_queue::{KeyedQueue, MonotoneQueue};
+
+mod indexed_heap;
+pub use indexed_heap::IndexedHeap;
+
+pub mod cycle;
+pub mod geometry;
+pub mod graph;
+pub mod linalg;
+pub mod poly;
+pub mod search;
 
 pub trait CollectExact<T> {
     type Error;
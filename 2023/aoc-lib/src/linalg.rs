@@ -0,0 +1,168 @@
+use num::{rational::Ratio, Integer, Signed, Zero};
+use thiserror::Error;
+
+/// Exact fraction, `i128` by default; use `num::BigInt` when entries get too large
+pub type Rational<T = i128> = Ratio<T>;
+
+/// Dense matrix stored as a list of rows
+pub type Matrix<T = i128> = Vec<Vec<Rational<T>>>;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LinalgError {
+    #[error("Matrix is {rows}x{cols} but the right-hand side has {len} entries")]
+    DimensionMismatch {
+        rows: usize,
+        cols: usize,
+        len: usize,
+    },
+    #[error("System has no unique solution")]
+    Singular,
+}
+
+/// Converts a matrix of integers to rationals
+pub fn to_rational<T: Clone + Integer>(matrix: &[Vec<T>]) -> Matrix<T> {
+    matrix
+        .iter()
+        .map(|row| row.iter().cloned().map(Ratio::from_integer).collect())
+        .collect()
+}
+
+/// Gauss-Jordan elimination, bringing the matrix to its reduced row echelon form.
+///
+/// Also returns the column of every pivot.
+pub fn row_reduce<T>(mut a: Matrix<T>) -> (Matrix<T>, Vec<usize>)
+where
+    T: Clone,
+    T: Integer,
+    T: Signed,
+{
+    let rows = a.len();
+    let cols = a.first().map_or(0, Vec::len);
+    let mut pivots = Vec::new();
+    for col in 0..cols {
+        let row = pivots.len();
+        let Some(pivot) = (row..rows).find(|&i| !a[i][col].is_zero()) else {
+            continue;
+        };
+        a.swap(row, pivot);
+        let p = a[row][col].clone();
+        for x in &mut a[row] {
+            *x = &*x / &p;
+        }
+        for i in 0..rows {
+            if i != row && !a[i][col].is_zero() {
+                let factor = a[i][col].clone();
+                let (pivot_row, other) = if i < row {
+                    let (head, tail) = a.split_at_mut(row);
+                    (&tail[0], &mut head[i])
+                } else {
+                    let (head, tail) = a.split_at_mut(i);
+                    (&head[row], &mut tail[0])
+                };
+                for (x, y) in other.iter_mut().zip(pivot_row) {
+                    *x = &*x - &factor * y;
+                }
+            }
+        }
+        pivots.push(col);
+        if pivots.len() == rows {
+            break;
+        }
+    }
+    (a, pivots)
+}
+
+/// Exact solution of the square system `a x = b`
+pub fn solve<T>(a: &Matrix<T>, b: &[Rational<T>]) -> Result<Vec<Rational<T>>, LinalgError>
+where
+    T: Clone,
+    T: Integer,
+    T: Signed,
+{
+    let rows = a.len();
+    let cols = a.first().map_or(0, Vec::len);
+    if b.len() != rows {
+        return Err(LinalgError::DimensionMismatch {
+            rows,
+            cols,
+            len: b.len(),
+        });
+    }
+    let augmented = a
+        .iter()
+        .zip(b)
+        .map(|(row, x)| {
+            let mut row = row.clone();
+            row.push(x.clone());
+            row
+        })
+        .collect::<Vec<_>>();
+    let (reduced, pivots) = row_reduce(augmented);
+    // Every unknown must have a pivot, and the right-hand side must not
+    if pivots.len() != cols || pivots.last().is_some_and(|&col| col == cols) {
+        return Err(LinalgError::Singular);
+    }
+    Ok(reduced[..cols]
+        .iter()
+        .map(|row| row[cols].clone())
+        .collect())
+}
+
+/// Exact least-squares solution of `a x = b`, solving the normal equations `aᵀa x = aᵀb`
+pub fn least_squares<T>(a: &Matrix<T>, b: &[Rational<T>]) -> Result<Vec<Rational<T>>, LinalgError>
+where
+    T: Clone,
+    T: Integer,
+    T: Signed,
+{
+    let rows = a.len();
+    let cols = a.first().map_or(0, Vec::len);
+    if b.len() != rows {
+        return Err(LinalgError::DimensionMismatch {
+            rows,
+            cols,
+            len: b.len(),
+        });
+    }
+    let dot = |f: &dyn Fn(usize) -> Rational<T>, g: &dyn Fn(usize) -> Rational<T>| {
+        (0..rows).fold(Rational::zero(), |acc, k| acc + f(k) * g(k))
+    };
+    let normal = (0..cols)
+        .map(|i| {
+            (0..cols)
+                .map(|j| dot(&|k| a[k][i].clone(), &|k| a[k][j].clone()))
+                .collect()
+        })
+        .collect::<Vec<_>>();
+    let rhs = (0..cols)
+        .map(|i| dot(&|k| a[k][i].clone(), &|k| b[k].clone()))
+        .collect::<Vec<_>>();
+    solve(&normal, &rhs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn systems() {
+        let a = to_rational(&[vec![2, 1, -1], vec![-3, -1, 2], vec![-2, 1, 2]]);
+        let b = [8, -11, -3].map(Rational::from_integer);
+        assert_eq!(
+            solve(&a, &b),
+            Ok([2, 3, -1].map(Rational::from_integer).to_vec())
+        );
+
+        let singular = to_rational(&[vec![1, 2], vec![2, 4]]);
+        let b = [1, 2].map(Rational::from_integer);
+        assert_eq!(solve(&singular, &b), Err(LinalgError::Singular));
+
+        // Fit y = c0 + c1 x on (0, 1), (1, 2), (2, 4)
+        let a = to_rational(&[vec![1, 0], vec![1, 1], vec![1, 2]]);
+        let b = [1, 2, 4].map(Rational::from_integer);
+        assert_eq!(
+            least_squares(&a, &b),
+            Ok(vec![Rational::new(5, 6), Rational::new(3, 2)])
+        );
+    }
+}
@@ -1,43 +1,162 @@
-use std::{mem::swap, ops::Sub};
+use std::{
+    mem::swap,
+    ops::{RangeInclusive, Shl, Shr, Sub},
+};
 
-pub fn gcd(mut n: u64, mut m: u64) -> u64 {
+use num::{BigInt, Integer, Num, PrimInt, Signed, Zero};
+use thiserror::Error;
+
+// Absolute value, which is a no-op for unsigned types, or `None` for `T::MIN` of signed ones
+fn checked_abs<T: PrimInt>(n: T) -> Option<T> {
+    if n < T::zero() {
+        T::zero().checked_sub(&n)
+    } else {
+        Some(n)
+    }
+}
+
+/// Non-negative GCD of any primitive integers, or `None` if it does not fit in `T`.
+///
+/// This only happens for signed types when both numbers are `0` or `T::MIN`.
+pub fn checked_gcd<T: PrimInt>(n: T, m: T) -> Option<T> {
     // Stein's binary GCD algorithm
-    // Base cases: gcd(n, 0) = gcd(0, n) = n
-    if n == 0 {
-        return m;
-    } else if m == 0 {
-        return n;
+    // Base cases: gcd(n, 0) = gcd(0, n) = |n|
+    if n.is_zero() {
+        return checked_abs(m);
+    } else if m.is_zero() {
+        return checked_abs(n);
     }
 
     // Extract common factor-2: gcd(2ⁱ n, 2ⁱ m) = 2ⁱ gcd(n, m)
-    // and reducing until odd gcd(2ⁱ n, m) = gcd(n, m) if m is odd
-    let k = {
-        let k_n = n.trailing_zeros();
-        let k_m = m.trailing_zeros();
-        n >>= k_n;
-        m >>= k_m;
-        k_n.min(k_m)
-    };
+    // and reducing until odd gcd(2ⁱ n, m) = gcd(n, m) if m is odd.
+    // Odd numbers are never T::MIN, so they can then be negated safely.
+    let k = n.trailing_zeros().min(m.trailing_zeros()) as usize;
+    let mut n = checked_abs(n >> n.trailing_zeros() as usize)?;
+    let mut m = checked_abs(m >> m.trailing_zeros() as usize)?;
 
     loop {
         // Invariant: n odd
-        debug_assert!(n % 2 == 1, "n = {} is even", n);
+        debug_assert!(n & T::one() == T::one(), "n is even");
 
         if n > m {
             swap(&mut n, &mut m);
         }
-        m -= n;
+        m = m - n;
 
-        if m == 0 {
-            return n << k;
+        if m.is_zero() {
+            let g = n << k;
+            return (g >> k == n).then_some(g);
         }
 
-        m >>= m.trailing_zeros();
+        m = m >> m.trailing_zeros() as usize;
+    }
+}
+
+/// Non-negative GCD of any primitive integers.
+///
+/// Panics if it does not fit in `T`, see [`checked_gcd`].
+pub fn gcd<T: PrimInt>(n: T, m: T) -> T {
+    checked_gcd(n, m).expect("GCD does not fit in the integer type")
+}
+
+/// Non-negative LCM, panics if it does not fit in `T`
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("LCM does not fit in the integer type")
+}
+
+/// LCM, or `None` if it does not fit in `T`
+pub fn checked_lcm<T: PrimInt>(a: T, b: T) -> Option<T> {
+    if a.is_zero() || b.is_zero() {
+        return Some(T::zero());
     }
+    // Dividing before multiplying to delay overflows
+    checked_abs(a / checked_gcd(a, b)?)?.checked_mul(&checked_abs(b)?)
 }
 
-pub fn lcm(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
+/// LCM of all the numbers, 1 if there is none
+pub fn lcm_all<T: PrimInt>(numbers: impl IntoIterator<Item = T>) -> T {
+    numbers.into_iter().fold(T::one(), lcm)
+}
+
+/// Largest `r` such that `r * r <= n`, `n` must not be negative.
+///
+/// Works for primitive integers as well as `BigInt`.
+pub fn isqrt<T>(n: T) -> T
+where
+    T: Num,
+    T: Ord,
+    T: Clone,
+    T: Shl<usize, Output = T>,
+    T: Shr<usize, Output = T>,
+{
+    assert!(n >= T::zero(), "square root of a negative number");
+    if n.is_zero() {
+        return n;
+    }
+    let mut bits = 0;
+    let mut m = n.clone();
+    while !m.is_zero() {
+        m = m >> 1;
+        bits += 1;
+    }
+    // Newton's method, starting above the root so that it decreases monotonically
+    let mut x = T::one() << (bits / 2 + 1);
+    loop {
+        let y = (x.clone() + n.clone() / x.clone()) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// Cube root rounded toward zero
+pub fn icbrt<T: PrimInt>(n: T) -> T {
+    if n < T::zero() {
+        // n = -(m + 1) with m >= 0, as -n might not fit in T
+        let m = T::zero() - (n + T::one());
+        let r = icbrt(m);
+        // cbrt(m + 1) is r + 1 iff (r + 1)³ = m + 1, i.e. m - r³ = 3r² + 3r
+        let three = T::one() + T::one() + T::one();
+        let gap = r
+            .checked_mul(&r)
+            .and_then(|r2| r2.checked_mul(&three))
+            .and_then(|r2| r2.checked_add(&(r * three)));
+        return if gap == Some(m - r * r * r) {
+            T::zero() - r - T::one()
+        } else {
+            T::zero() - r
+        };
+    }
+    if n.is_zero() {
+        return n;
+    }
+    let bits = (T::zero().count_zeros() - n.leading_zeros()) as usize;
+    let mut x = T::one() << (bits / 3 + 1);
+    let three = T::one() + T::one() + T::one();
+    loop {
+        let y = (x + x + n / (x * x)) / three;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// `base^exp mod modulus`, with 128-bit intermediates. Panics if `modulus` is zero
+pub fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result as u64
 }
 
 pub fn abs_diff<T>(a: T, b: T) -> <T as Sub>::Output
@@ -51,3 +170,455 @@ where
         a - b
     }
 }
+
+/// Returns `(g, x, y)` such that `a * x + b * y = g = gcd(a, b)`
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (T::one(), T::zero());
+    let (mut y0, mut y1) = (T::zero(), T::one());
+    while !r1.is_zero() {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < T::zero() {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Inverse of `a` modulo `m`, if `a` and `m` are coprime
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    let (g, x, _) = extended_gcd((a % m) as i128, m as i128);
+    (g == 1).then(|| x.mod_floor(&(m as i128)) as u64)
+}
+
+/// Smallest `x` satisfying `x = r (mod m)` for all `(r, m)`, along with the lcm of all moduli.
+///
+/// Moduli do not need to be coprime. Returns `None` if the congruences are incompatible,
+/// if a modulus is zero, or if the lcm of the moduli does not fit in a `u128`.
+pub fn crt(congruences: impl IntoIterator<Item = (u64, u64)>) -> Option<(u128, u128)> {
+    let mut x = 0_u128;
+    let mut modulus = 1_u128;
+    for (r, m) in congruences {
+        if m == 0 {
+            return None;
+        }
+        let (r, m) = (r as u128 % m as u128, m as u128);
+        let g = gcd(modulus, m);
+        let diff = (r + m - x % m) % m;
+        if !diff.is_multiple_of(g) {
+            return None;
+        }
+        // Solve x + modulus * k = r (mod m), i.e. (modulus / g) * k = diff / g (mod m / g)
+        let m_g = m / g;
+        let inverse = mod_inverse((modulus / g % m_g) as u64, m_g as u64)? as u128;
+        let k = diff / g % m_g * inverse % m_g;
+        // As x < modulus and k < m_g, x + modulus * k < modulus * m_g
+        let lcm = modulus.checked_mul(m_g)?;
+        x += modulus * k;
+        modulus = lcm;
+    }
+    Some((x, modulus))
+}
+
+/// Times at which an eventually periodic event happens.
+///
+/// `hits` are the times before `offset + period`: those before `offset` happen only once,
+/// the others are repeated every `period`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CycleHits {
+    pub offset: u64,
+    pub period: u64,
+    pub hits: Vec<u64>,
+}
+
+impl CycleHits {
+    /// Panics if `period` is zero
+    pub fn hits_at(&self, t: u64) -> bool {
+        assert!(self.period > 0, "cycle period must be positive");
+        if t - self.offset.min(t) < self.period {
+            self.hits.contains(&t)
+        } else {
+            let t = self.offset + (t - self.offset) % self.period;
+            self.hits.contains(&t)
+        }
+    }
+}
+
+/// Earliest time at which all cycles hit simultaneously, `None` if a period is zero
+pub fn align_cycles(cycles: &[CycleHits]) -> Option<u64> {
+    if cycles.iter().any(|cycle| cycle.period == 0) {
+        return None;
+    }
+
+    // Hits in the prefix of a cycle are candidates on their own
+    let mut best = cycles
+        .iter()
+        .flat_map(|cycle| cycle.hits.iter().filter(|&&t| t < cycle.offset))
+        .copied()
+        .filter(|&t| cycles.iter().all(|cycle| cycle.hits_at(t)))
+        .min();
+
+    // Otherwise, combine periodic hits of every cycle
+    let start = cycles.iter().map(|cycle| cycle.offset).max()?;
+    let mut partial = vec![Vec::new()];
+    for cycle in cycles {
+        partial = partial
+            .iter()
+            .flat_map(|congruences| {
+                cycle
+                    .hits
+                    .iter()
+                    .filter(|&&t| t >= cycle.offset)
+                    .map(move |&t| {
+                        let mut congruences = congruences.clone();
+                        congruences.push((t % cycle.period, cycle.period));
+                        congruences
+                    })
+            })
+            .filter(|congruences| crt(congruences.iter().copied()).is_some())
+            .collect();
+    }
+    for congruences in partial {
+        let Some((x, modulus)) = crt(congruences) else {
+            continue;
+        };
+        let start = start as u128;
+        let t = if x >= start {
+            Some(x)
+        } else {
+            (start - x)
+                .div_ceil(modulus)
+                .checked_mul(modulus)
+                .and_then(|shift| shift.checked_add(x))
+        };
+        // Alignments past u64::MAX are ignored
+        if let Some(t) = t.and_then(|t| u64::try_from(t).ok()) {
+            best = Some(best.map_or(t, |best| best.min(t)));
+        }
+    }
+    best
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QuadraticError {
+    #[error("The set of solutions is unbounded")]
+    Unbounded,
+    #[error("The solutions do not fit in the integer type")]
+    Overflow,
+}
+
+type QuadraticRange<T> = Result<Option<RangeInclusive<T>>, QuadraticError>;
+
+// Integers where `a x² + b x + c < 0`, or `<= 0` if `inclusive`
+fn quadratic_range(a: BigInt, b: BigInt, c: BigInt, inclusive: bool) -> QuadraticRange<BigInt> {
+    let condition = |x: &BigInt| {
+        let y = (&a * x + &b) * x + &c;
+        y.is_negative() || (inclusive && y.is_zero())
+    };
+    if !a.is_positive() {
+        // Either a half-line, everything, or nothing for a constant
+        return if a.is_zero() && b.is_zero() && !condition(&c) {
+            Ok(None)
+        } else {
+            Err(QuadraticError::Unbounded)
+        };
+    }
+    let discriminant = &b * &b - BigInt::from(4) * &a * &c;
+    if discriminant.is_negative() {
+        return Ok(None);
+    }
+    // Estimates within one of the actual bounds, refined by evaluating the quadratic
+    let root = isqrt(discriminant);
+    let mut lo = (-&b - &root).div_floor(&(BigInt::from(2) * &a));
+    let mut hi = (-&b + &root).div_floor(&(BigInt::from(2) * &a));
+    while condition(&(&lo - 1)) {
+        lo -= 1;
+    }
+    while condition(&(&hi + 1)) {
+        hi += 1;
+    }
+    while lo <= hi && !condition(&lo) {
+        lo += 1;
+    }
+    while lo <= hi && !condition(&hi) {
+        hi -= 1;
+    }
+    Ok((lo <= hi).then_some(lo..=hi))
+}
+
+// Converts the bounds of the range to `T`
+fn narrow_range<T: TryFrom<BigInt>>(range: QuadraticRange<BigInt>) -> QuadraticRange<T> {
+    let Some(range) = range? else {
+        return Ok(None);
+    };
+    let (lo, hi) = range.into_inner();
+    match (T::try_from(lo), T::try_from(hi)) {
+        (Ok(lo), Ok(hi)) => Ok(Some(lo..=hi)),
+        _ => Err(QuadraticError::Overflow),
+    }
+}
+
+/// Integers `x` such that `a x² + b x + c > 0`, `a` must be negative for the range to be bounded
+pub fn quadratic_positive_range<T: TryFrom<BigInt>>(
+    a: impl Into<BigInt>,
+    b: impl Into<BigInt>,
+    c: impl Into<BigInt>,
+) -> QuadraticRange<T> {
+    narrow_range(quadratic_range(-a.into(), -b.into(), -c.into(), false))
+}
+
+/// Integers `x` such that `a x² + b x + c >= 0`, `a` must be negative
+pub fn quadratic_non_negative_range<T: TryFrom<BigInt>>(
+    a: impl Into<BigInt>,
+    b: impl Into<BigInt>,
+    c: impl Into<BigInt>,
+) -> QuadraticRange<T> {
+    narrow_range(quadratic_range(-a.into(), -b.into(), -c.into(), true))
+}
+
+/// Integers `x` such that `a x² + b x + c < 0`, `a` must be positive
+pub fn quadratic_negative_range<T: TryFrom<BigInt>>(
+    a: impl Into<BigInt>,
+    b: impl Into<BigInt>,
+    c: impl Into<BigInt>,
+) -> QuadraticRange<T> {
+    narrow_range(quadratic_range(a.into(), b.into(), c.into(), false))
+}
+
+/// Integers `x` such that `a x² + b x + c <= 0`, `a` must be positive
+pub fn quadratic_non_positive_range<T: TryFrom<BigInt>>(
+    a: impl Into<BigInt>,
+    b: impl Into<BigInt>,
+    c: impl Into<BigInt>,
+) -> QuadraticRange<T> {
+    narrow_range(quadratic_range(a.into(), b.into(), c.into(), true))
+}
+
+/// Number of integers strictly between the roots of `a x² + b x + c`,
+/// where it is positive if `a < 0`, or negative if `a > 0`
+pub fn count_integer_solutions_quadratic<T: TryFrom<BigInt>>(
+    a: impl Into<BigInt>,
+    b: impl Into<BigInt>,
+    c: impl Into<BigInt>,
+) -> Result<T, QuadraticError> {
+    let (a, b, c) = (a.into(), b.into(), c.into());
+    let range = if a.is_negative() {
+        quadratic_range(-a, -b, -c, false)
+    } else {
+        quadratic_range(a, b, c, false)
+    }?;
+    let count = range.map_or(BigInt::zero(), |range| range.end() - range.start() + 1);
+    T::try_from(count).map_err(|_| QuadraticError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generic() {
+        assert_eq!(gcd(12_u32, 18), 6);
+        assert_eq!(gcd(-12_i64, 18), 6);
+        assert_eq!(lcm(-4_i8, 6), 12);
+        assert_eq!(lcm_all([2_u128, 3, 4, 5]), 60);
+        assert_eq!(checked_lcm(u64::MAX, 2), None);
+        assert_eq!(checked_lcm(1_u64 << 62, 1 << 63), Some(1 << 63));
+        for n in 0..2000_u32 {
+            let r = isqrt(n);
+            assert!(r * r <= n && n < (r + 1) * (r + 1));
+            let r = icbrt(n);
+            assert!(r * r * r <= n && n < (r + 1) * (r + 1) * (r + 1));
+        }
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+        assert_eq!(icbrt(u64::MAX), 2642245);
+        assert_eq!(icbrt(-27_i32), -3);
+        assert_eq!(pow_mod(2, 64, u64::MAX), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn pow_mod_zero() {
+        pow_mod(2, 0, 0);
+    }
+
+    #[test]
+    fn extremes() {
+        assert_eq!(gcd(i64::MIN, 3), 1);
+        assert_eq!(gcd(6, i64::MIN), 2);
+        assert_eq!(gcd(i64::MIN, 1 << 62), 1 << 62);
+        assert_eq!(gcd(i64::MAX, i64::MIN), 1);
+        assert_eq!(gcd(u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(checked_gcd(i64::MIN, 0), None);
+        assert_eq!(checked_gcd(i8::MIN, i8::MIN), None);
+        assert_eq!(checked_gcd(i8::MIN, -64), Some(64));
+
+        assert_eq!(lcm(-64_i8, 2), 64);
+        assert_eq!(checked_lcm(i64::MIN, 3), None);
+        assert_eq!(checked_lcm(i64::MIN, 1), None);
+        assert_eq!(checked_lcm(i64::MAX, 1), Some(i64::MAX));
+        assert_eq!(checked_lcm(u8::MAX, 5), Some(u8::MAX));
+
+        assert_eq!(isqrt(i64::MAX), 3_037_000_499);
+        assert_eq!(isqrt(u8::MAX), 15);
+        assert_eq!(icbrt(i8::MIN), -5);
+        assert_eq!(icbrt(i8::MAX), 5);
+        assert_eq!(icbrt(-125_i8), -5);
+        assert_eq!(icbrt(-124_i8), -4);
+        assert_eq!(icbrt(i64::MIN), -(1 << 21));
+        assert_eq!(icbrt(i64::MIN + 1), -(1 << 21) + 1);
+        assert_eq!(icbrt(u128::MAX), 6_981_463_658_331);
+        for n in -1000..0_i32 {
+            let r = icbrt(n);
+            assert!(r * r * r >= n && n > (r - 1) * (r - 1) * (r - 1));
+        }
+        assert_eq!(pow_mod(3, 200, 1_000_000_007), 136_318_165);
+    }
+
+    #[test]
+    fn quadratic() {
+        // Boat races: hold * (time - hold) > distance
+        assert_eq!(quadratic_positive_range(-1, 7, -9), Ok(Some(2..=5)));
+        assert_eq!(quadratic_positive_range(-1, 30, -200), Ok(Some(11..=19)));
+        assert_eq!(
+            quadratic_non_negative_range(-1, 30, -200),
+            Ok(Some(10..=20))
+        );
+        assert_eq!(
+            count_integer_solutions_quadratic(-1, 71530, -940200),
+            Ok(71503_u64)
+        );
+        assert_eq!(quadratic_negative_range::<i32>(1, 0, 1), Ok(None));
+        assert_eq!(quadratic_non_positive_range(1, -2, 1), Ok(Some(1..=1)));
+        assert_eq!(quadratic_negative_range::<i32>(1, -2, 1), Ok(None));
+        assert_eq!(quadratic_negative_range(4, 0, -1), Ok(Some(0..=0)));
+
+        let time = u128::MAX;
+        let range = quadratic_positive_range(-1, time, 0);
+        assert_eq!(range, Ok(Some(1..=time - 1)));
+        assert_eq!(
+            count_integer_solutions_quadratic(-1, time, -BigInt::from(time)),
+            Ok(time - 3)
+        );
+        assert_eq!(
+            count_integer_solutions_quadratic(1, 0, -(1_i128 << 100)),
+            Ok((1_u64 << 51) - 1)
+        );
+    }
+
+    #[test]
+    fn quadratic_edge_cases() {
+        // Linear and constant functions
+        assert_eq!(
+            quadratic_positive_range::<i64>(0, 1, 0),
+            Err(QuadraticError::Unbounded)
+        );
+        assert_eq!(
+            quadratic_positive_range::<i64>(0, 0, 1),
+            Err(QuadraticError::Unbounded)
+        );
+        assert_eq!(quadratic_positive_range::<i64>(0, 0, 0), Ok(None));
+        assert_eq!(
+            count_integer_solutions_quadratic::<u64>(0, 0, -1),
+            Err(QuadraticError::Unbounded)
+        );
+        assert_eq!(
+            quadratic_negative_range::<i64>(-1, 0, 1),
+            Err(QuadraticError::Unbounded)
+        );
+
+        // Roots outside of the output type are not an empty range
+        let range = quadratic_positive_range::<i64>(-1, u64::MAX, 0);
+        assert_eq!(range, Err(QuadraticError::Overflow));
+        assert_eq!(
+            count_integer_solutions_quadratic::<u8>(-1, 1000, 0),
+            Err(QuadraticError::Overflow)
+        );
+        assert_eq!(isqrt(BigInt::from(u128::MAX) * u128::MAX), u128::MAX.into());
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // The lcm needs 92 bits
+        let (p, q) = ((1 << 61) - 1, (1 << 31) - 1);
+        let (x, lcm) = crt([(1, p), (2, q)]).unwrap();
+        assert_eq!(lcm, p as u128 * q as u128);
+        assert_eq!((x % p as u128, x % q as u128), (1, 2));
+        assert_eq!(
+            crt([(0, u64::MAX), (0, u64::MAX - 1), (0, u64::MAX - 2)]),
+            None
+        );
+        assert_eq!(crt([(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+        assert_eq!(crt([(1, 4), (0, 0)]), None);
+    }
+
+    #[test]
+    fn cycles() {
+        let cycles = [
+            CycleHits {
+                offset: 1,
+                period: 2,
+                hits: vec![2],
+            },
+            CycleHits {
+                offset: 1,
+                period: 6,
+                hits: vec![3, 6],
+            },
+        ];
+        assert_eq!(align_cycles(&cycles), Some(6));
+
+        let cycles = [
+            CycleHits {
+                offset: 5,
+                period: 4,
+                hits: vec![1, 7],
+            },
+            CycleHits {
+                offset: 0,
+                period: 1,
+                hits: vec![0],
+            },
+        ];
+        assert_eq!(align_cycles(&cycles), Some(1));
+        assert_eq!(align_cycles(&cycles[..1]), Some(1));
+        assert_eq!(align_cycles(&[]), None);
+
+        let zero = CycleHits {
+            offset: 0,
+            period: 0,
+            hits: vec![0],
+        };
+        assert_eq!(align_cycles(&[cycles[1].clone(), zero]), None);
+
+        // Periods with an lcm beyond u64
+        let periods = [(1 << 61) - 1, (1 << 31) - 1, 3];
+        let cycles = periods.map(|period| CycleHits {
+            offset: 0,
+            period,
+            hits: vec![1],
+        });
+        assert_eq!(align_cycles(&cycles), Some(1));
+        let cycles = periods.map(|period| CycleHits {
+            offset: 0,
+            period,
+            hits: vec![period - 1],
+        });
+        assert_eq!(align_cycles(&cycles), None);
+        let cycle = CycleHits {
+            offset: u64::MAX - 1,
+            period: 5,
+            hits: vec![u64::MAX - 1],
+        };
+        assert!(cycle.hits_at(u64::MAX - 1));
+        assert!(!cycle.hits_at(u64::MAX));
+    }
+}
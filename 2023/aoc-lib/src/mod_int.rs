@@ -0,0 +1,224 @@
+use std::{
+    fmt::Display,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use crate::{mod_inverse, pow_mod};
+
+/// Integer modulo `M`, known at compile time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ModInt<const M: u64> {
+    value: u64,
+}
+
+/// Integer modulo a modulus only known at runtime.
+///
+/// The modulus must be positive. Operations between integers with different moduli panic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DynModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl<const M: u64> ModInt<M> {
+    pub fn new(value: u64) -> Self {
+        const { assert!(M > 0, "modulus must be positive") };
+        Self { value: value % M }
+    }
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+    pub fn modulus(&self) -> u64 {
+        M
+    }
+    fn with_value(&self, value: u64) -> Self {
+        Self { value }
+    }
+    fn check(&self, _other: &Self) {}
+}
+
+impl DynModInt {
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "modulus must be positive");
+        Self {
+            value: value % modulus,
+            modulus,
+        }
+    }
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+    fn with_value(&self, value: u64) -> Self {
+        Self {
+            value,
+            modulus: self.modulus,
+        }
+    }
+    fn check(&self, other: &Self) {
+        assert_eq!(self.modulus, other.modulus, "mismatched moduli");
+    }
+}
+
+impl<const M: u64> From<u64> for ModInt<M> {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+macro_rules! implement_mod_int {
+    ([$($generics:tt)*] $type:ty) => {
+        impl<$($generics)*> $type {
+            /// `self^exp`
+            pub fn pow(self, exp: u64) -> Self {
+                self.with_value(pow_mod(self.value, exp, self.modulus()))
+            }
+            /// Multiplicative inverse, if the value is coprime with the modulus
+            pub fn inverse(self) -> Option<Self> {
+                mod_inverse(self.value, self.modulus()).map(|value| self.with_value(value))
+            }
+        }
+
+        impl<$($generics)*> Add for $type {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                self.check(&rhs);
+                let sum = self.value as u128 + rhs.value as u128;
+                self.with_value((sum % self.modulus() as u128) as u64)
+            }
+        }
+        impl<$($generics)*> Sub for $type {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                self + -rhs
+            }
+        }
+        impl<$($generics)*> Neg for $type {
+            type Output = Self;
+            fn neg(self) -> Self {
+                if self.value == 0 {
+                    self
+                } else {
+                    self.with_value(self.modulus() - self.value)
+                }
+            }
+        }
+        impl<$($generics)*> Mul for $type {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                self.check(&rhs);
+                let product = self.value as u128 * rhs.value as u128;
+                self.with_value((product % self.modulus() as u128) as u64)
+            }
+        }
+        impl<$($generics)*> Div for $type {
+            type Output = Self;
+            /// Panics if `rhs` is not invertible
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * rhs.inverse().expect("division by a non-invertible value")
+            }
+        }
+
+        impl<$($generics)*> AddAssign for $type {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+        impl<$($generics)*> SubAssign for $type {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+        impl<$($generics)*> MulAssign for $type {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+        impl<$($generics)*> DivAssign for $type {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($generics)*> Display for $type {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.value.fmt(f)
+            }
+        }
+    };
+}
+
+implement_mod_int!([const M: u64] ModInt<M>);
+implement_mod_int!([] DynModInt);
+
+// The runtime modulus is unknown for empty iterators, so only the static variant implements
+// `Sum` and `Product`, `DynModInt` taking the modulus explicitly
+impl<const M: u64> Sum for ModInt<M> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(0), Add::add)
+    }
+}
+impl<const M: u64> Product for ModInt<M> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::new(1), Mul::mul)
+    }
+}
+
+impl DynModInt {
+    /// Sum of the values, all being modulo `modulus`
+    pub fn sum_mod(iter: impl IntoIterator<Item = Self>, modulus: u64) -> Self {
+        iter.into_iter().fold(Self::new(0, modulus), Add::add)
+    }
+    /// Product of the values, all being modulo `modulus`
+    pub fn product_mod(iter: impl IntoIterator<Item = Self>, modulus: u64) -> Self {
+        iter.into_iter().fold(Self::new(1, modulus), Mul::mul)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Mod7 = ModInt<7>;
+
+    #[test]
+    fn arithmetic() {
+        let a = Mod7::new(5);
+        let b = Mod7::new(4);
+        assert_eq!((a + b).value(), 2);
+        assert_eq!((b - a).value(), 6);
+        assert_eq!((a * b).value(), 6);
+        assert_eq!((a / b) * b, a);
+        assert_eq!(a.pow(6), Mod7::new(1));
+        assert_eq!((1..7).map(Mod7::new).product::<Mod7>(), Mod7::new(6));
+        assert_eq!((1..7).map(Mod7::new).sum::<Mod7>().to_string(), "0");
+
+        let big = ModInt::<{ u64::MAX - 58 }>::new(u64::MAX - 59);
+        assert_eq!((big * big).value(), 1);
+        assert_eq!((big + big).value(), u64::MAX - 60);
+
+        let a = DynModInt::new(10, 12);
+        assert_eq!(a.inverse(), None);
+        assert_eq!(DynModInt::new(5, 12).inverse(), Some(DynModInt::new(5, 12)));
+        assert_eq!(-a, DynModInt::new(2, 12));
+
+        let values = (1..7).map(|n| DynModInt::new(n, 7));
+        assert_eq!(
+            DynModInt::product_mod(values.clone(), 7),
+            DynModInt::new(6, 7)
+        );
+        assert_eq!(DynModInt::sum_mod(values, 7).value(), 0);
+        assert_eq!(DynModInt::sum_mod([], 5), DynModInt::new(0, 5));
+        assert_eq!(DynModInt::product_mod([], 5), DynModInt::new(1, 5));
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be positive")]
+    fn zero_modulus() {
+        DynModInt::new(3, 0);
+    }
+}
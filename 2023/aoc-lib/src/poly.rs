@@ -0,0 +1,182 @@
+use num::{rational::Ratio, CheckedAdd, CheckedMul, Integer, One, PrimInt, Signed, Zero};
+
+/// Leading forward differences `Δᵏf(0)` of a sequence sampled at `x = 0, 1, 2, ...`,
+/// without the trailing zeros, or `None` on overflow.
+pub fn forward_differences<T>(values: &[T]) -> Option<Vec<T>>
+where
+    T: PrimInt,
+    T: Signed,
+{
+    let mut row = values.to_vec();
+    let mut differences = Vec::with_capacity(values.len());
+    while !row.is_empty() {
+        differences.push(row[0]);
+        row = row
+            .windows(2)
+            .map(|w| w[1].checked_sub(&w[0]))
+            .collect::<Option<_>>()?;
+    }
+    while differences.last().is_some_and(|d| d.is_zero()) {
+        differences.pop();
+    }
+    Some(differences)
+}
+
+/// Value at `x` of the lowest degree polynomial going through `values` sampled at
+/// `x = 0, 1, 2, ...`, or `None` on overflow.
+///
+/// Uses Newton's forward formula `f(x) = Σ C(x, k) Δᵏf(0)`, so `x` can be huge or negative.
+pub fn newton_eval<T>(values: &[T], x: T) -> Option<T>
+where
+    T: PrimInt,
+    T: Signed,
+{
+    let mut result = T::zero();
+    let mut binomial = T::one();
+    for (k, d) in forward_differences(values)?.into_iter().enumerate() {
+        if k > 0 {
+            // C(x, k) = C(x, k - 1) * (x - k + 1) / k, the division being exact
+            let k = T::from(k)?;
+            let factor = x.checked_sub(&(k - T::one()))?;
+            let (q, r) = (binomial / k, binomial % k);
+            binomial = q
+                .checked_mul(&factor)?
+                .checked_add(&(r.checked_mul(&factor)? / k))?;
+        }
+        result = result.checked_add(&binomial.checked_mul(&d)?)?;
+    }
+    Some(result)
+}
+
+/// Next value of the sequence
+pub fn extrapolate<T>(values: &[T]) -> Option<T>
+where
+    T: PrimInt,
+    T: Signed,
+{
+    newton_eval(values, T::from(values.len())?)
+}
+
+/// Value before the first one of the sequence
+pub fn extrapolate_backward<T>(values: &[T]) -> Option<T>
+where
+    T: PrimInt,
+    T: Signed,
+{
+    newton_eval(values, -T::one())
+}
+
+/// Polynomial with exact rational coefficients, lowest degree first
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polynomial<T: Clone + Integer = i128> {
+    coefficients: Vec<Ratio<T>>,
+}
+
+impl<T: Clone + Integer> Polynomial<T> {
+    pub fn new(mut coefficients: Vec<Ratio<T>>) -> Self {
+        while coefficients.last().is_some_and(|c| c.is_zero()) {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+
+    /// Lagrange interpolation through the points, `None` if two points share the same `x`
+    pub fn interpolate(points: &[(T, T)]) -> Option<Self> {
+        let mut coefficients = vec![Ratio::zero(); points.len()];
+        for (i, (xi, yi)) in points.iter().enumerate() {
+            // Basis polynomial: Π (x - xj) / (xi - xj) for j != i
+            let mut basis = vec![Ratio::one()];
+            let mut denominator = T::one();
+            for (j, (xj, _)) in points.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                if xi == xj {
+                    return None;
+                }
+                basis.push(Ratio::zero());
+                for k in (0..basis.len()).rev() {
+                    let shifted = if k > 0 {
+                        basis[k - 1].clone()
+                    } else {
+                        Ratio::zero()
+                    };
+                    basis[k] = shifted - basis[k].clone() * Ratio::from_integer(xj.clone());
+                }
+                denominator = denominator * (xi.clone() - xj.clone());
+            }
+            let scale = Ratio::new(yi.clone(), denominator);
+            for (c, b) in coefficients.iter_mut().zip(basis) {
+                *c = c.clone() + b * scale.clone();
+            }
+        }
+        Some(Self::new(coefficients))
+    }
+
+    pub fn coefficients(&self) -> &[Ratio<T>] {
+        &self.coefficients
+    }
+    /// Degree of the polynomial, `None` for the null polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Exact value at `x`
+    pub fn eval(&self, x: T) -> Ratio<T> {
+        let x = Ratio::from_integer(x);
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Ratio::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Value at `x`, or `None` on overflow or if it is not an integer
+    pub fn checked_eval(&self, x: T) -> Option<T>
+    where
+        T: CheckedAdd,
+        T: CheckedMul,
+    {
+        let x = Ratio::from_integer(x);
+        let mut acc = Ratio::zero();
+        for c in self.coefficients.iter().rev() {
+            acc = acc.checked_mul(&x)?.checked_add(c)?;
+        }
+        acc.is_integer().then(|| acc.to_integer())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolation() {
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45]), Some(68));
+        assert_eq!(extrapolate_backward(&[10, 13, 16, 21, 30, 45]), Some(5));
+        assert_eq!(extrapolate::<i32>(&[]), Some(0));
+        // Triangular numbers: x (x + 1) / 2
+        let x = 1_000_000_000_i64;
+        assert_eq!(newton_eval(&[0, 1, 3, 6], x), Some(x * (x + 1) / 2));
+        assert_eq!(newton_eval(&[0_i64, 1, 8, 27], x), None);
+
+        assert_eq!(forward_differences(&[1, 4, 9, 16]), Some(vec![1, 3, 2]));
+        assert_eq!(forward_differences(&[i64::MAX, i64::MIN]), None);
+        assert_eq!(extrapolate(&[i64::MAX, i64::MIN]), None);
+        assert_eq!(extrapolate_backward(&[0, i8::MAX, i8::MIN]), None);
+        assert_eq!(extrapolate(&[i64::MAX - 2, i64::MAX - 1]), Some(i64::MAX));
+    }
+
+    #[test]
+    fn lagrange() {
+        let p = Polynomial::interpolate(&[(1, 4), (3, 10), (-1, 6)]).unwrap();
+        assert_eq!(p.coefficients(), &[4, -1, 1].map(Ratio::from_integer));
+        assert_eq!(p.degree(), Some(2));
+        assert_eq!(p.checked_eval(5), Some(24));
+        assert_eq!(p.checked_eval(1_i128 << 70), None);
+
+        let p = Polynomial::interpolate(&[(0, 0), (2, 1)]).unwrap();
+        assert_eq!(p.eval(1), Ratio::new(1, 2));
+        assert_eq!(p.checked_eval(1), None);
+        assert_eq!(Polynomial::interpolate(&[(1, 2), (1, 3)]), None);
+    }
+}
@@ -0,0 +1,103 @@
+use num::{PrimInt, Unsigned};
+
+/// Monotone priority queue for integer keys.
+///
+/// Elements are spread into buckets according to the highest bit differing from the last
+/// popped key. Keys pushed must never be lower than the last popped key.
+#[derive(Debug, Clone)]
+pub struct RadixHeap<K, T> {
+    last: K,
+    len: usize,
+    buckets: Vec<Vec<(K, T)>>,
+}
+
+impl<K: PrimInt + Unsigned, T> Default for RadixHeap<K, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PrimInt + Unsigned, T> RadixHeap<K, T> {
+    pub fn new() -> Self {
+        let nbits = K::zero().count_zeros() as usize;
+        Self {
+            last: K::zero(),
+            len: 0,
+            buckets: (0..=nbits).map(|_| Vec::new()).collect(),
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    pub fn clear(&mut self) {
+        self.last = K::zero();
+        self.len = 0;
+        for bucket in &mut self.buckets {
+            bucket.clear();
+        }
+    }
+
+    // Index of the highest bit differing from the last popped key, plus one
+    fn bucket(&self, key: K) -> usize {
+        let diff = key ^ self.last;
+        diff.count_ones() as usize + diff.count_zeros() as usize - diff.leading_zeros() as usize
+    }
+
+    pub fn push(&mut self, key: K, value: T) {
+        assert!(
+            key >= self.last,
+            "Pushed a key lower than the last popped one in a monotone queue"
+        );
+        let i = self.bucket(key);
+        self.buckets[i].push((key, value));
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<(K, T)> {
+        if self.buckets[0].is_empty() {
+            let i = self.buckets.iter().position(|bucket| !bucket.is_empty())?;
+            let bucket = std::mem::take(&mut self.buckets[i]);
+            self.last = bucket.iter().map(|(key, _)| *key).min()?;
+            for (key, value) in bucket {
+                let j = self.bucket(key);
+                self.buckets[j].push((key, value));
+            }
+        }
+        self.len -= 1;
+        self.buckets[0].pop()
+    }
+
+    pub fn peek(&self) -> Option<(K, &T)> {
+        let bucket = self.buckets.iter().find(|bucket| !bucket.is_empty())?;
+        bucket
+            .iter()
+            .min_by_key(|(key, _)| *key)
+            .map(|(key, value)| (*key, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_output() {
+        let mut heap = RadixHeap::<u32, usize>::new();
+        let mut popped = Vec::new();
+        for (i, key) in [5, 3, 17, 3, 0, 1024, 9].into_iter().enumerate() {
+            heap.push(key, i);
+        }
+        popped.push(heap.pop().unwrap().0);
+        popped.push(heap.pop().unwrap().0);
+        heap.push(4, 7);
+        heap.push(3, 8);
+        while let Some((key, _)) = heap.pop() {
+            popped.push(key);
+        }
+        assert_eq!(popped, vec![0, 3, 3, 3, 4, 5, 9, 17, 1024]);
+        assert!(heap.is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+use std::{cmp::Ordering, collections::BinaryHeap, hash::Hash};
+
+use hashbrown::HashMap;
+use num::Zero;
+
+/// Storage for the best known cost of the states, and the state they have been reached from
+pub trait SearchStore<S, C> {
+    fn best(&self, state: &S) -> Option<C>;
+    fn record(&mut self, state: S, cost: C, parent: Option<S>);
+    fn parent(&self, state: &S) -> Option<&S>;
+}
+
+#[derive(Debug, Clone)]
+pub struct HashStore<S, C> {
+    data: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S, C> Default for HashStore<S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, C> HashStore<S, C> {
+    pub fn new() -> Self {
+        Self {
+            data: HashMap::new(),
+        }
+    }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            data: HashMap::with_capacity(capacity),
+        }
+    }
+}
+
+impl<S, C> SearchStore<S, C> for HashStore<S, C>
+where
+    S: Hash,
+    S: Eq,
+    C: Copy,
+{
+    fn best(&self, state: &S) -> Option<C> {
+        self.data.get(state).map(|(cost, _)| *cost)
+    }
+    fn record(&mut self, state: S, cost: C, parent: Option<S>) {
+        self.data.insert(state, (cost, parent));
+    }
+    fn parent(&self, state: &S) -> Option<&S> {
+        self.data.get(state).and_then(|(_, parent)| parent.as_ref())
+    }
+}
+
+/// Store indexed by a user-provided key, for state spaces that can be densely enumerated
+#[derive(Debug, Clone)]
+pub struct DenseStore<S, C, K> {
+    key: K,
+    data: Vec<Option<(C, Option<S>)>>,
+}
+
+impl<S, C, K> DenseStore<S, C, K>
+where
+    K: Fn(&S) -> usize,
+{
+    pub fn new(size: usize, key: K) -> Self {
+        let mut data = Vec::with_capacity(size);
+        data.resize_with(size, || None);
+        Self { key, data }
+    }
+}
+
+impl<S, C, K> SearchStore<S, C> for DenseStore<S, C, K>
+where
+    K: Fn(&S) -> usize,
+    C: Copy,
+{
+    fn best(&self, state: &S) -> Option<C> {
+        self.data[(self.key)(state)].as_ref().map(|(cost, _)| *cost)
+    }
+    fn record(&mut self, state: S, cost: C, parent: Option<S>) {
+        let idx = (self.key)(&state);
+        self.data[idx] = Some((cost, parent));
+    }
+    fn parent(&self, state: &S) -> Option<&S> {
+        self.data[(self.key)(state)]
+            .as_ref()
+            .and_then(|(_, parent)| parent.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearchResult<S, C> {
+    pub cost: C,
+    pub path: Vec<S>,
+}
+
+// Heap entry ordered by reversed priority, so that BinaryHeap pops the smallest one first
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: PartialEq> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+impl<S, C: Eq> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    S: Hash,
+    S: Eq,
+    C: Copy,
+    C: Ord,
+    C: Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_with_store(&mut HashStore::new(), start, successors, goal, |_| {
+        C::zero()
+    })
+}
+
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> C,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    S: Hash,
+    S: Eq,
+    C: Copy,
+    C: Ord,
+    C: Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_with_store(&mut HashStore::new(), start, successors, goal, heuristic)
+}
+
+pub fn dijkstra_with_store<S, C, I>(
+    store: &mut impl SearchStore<S, C>,
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    C: Copy,
+    C: Ord,
+    C: Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar_with_store(store, start, successors, goal, |_| C::zero())
+}
+
+/// A* search from `start` until a state satisfying `goal` is popped.
+///
+/// The heuristic must be admissible (never overestimate the remaining cost)
+/// for the returned path to be optimal.
+pub fn astar_with_store<S, C, I>(
+    store: &mut impl SearchStore<S, C>,
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> C,
+) -> Option<SearchResult<S, C>>
+where
+    S: Clone,
+    C: Copy,
+    C: Ord,
+    C: Zero,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::new();
+
+    store.record(start.clone(), C::zero(), None);
+    heap.push(Entry {
+        priority: heuristic(&start),
+        cost: C::zero(),
+        state: start,
+    });
+
+    while let Some(Entry { cost, state, .. }) = heap.pop() {
+        // Skip outdated entries
+        if store.best(&state).is_some_and(|best| best < cost) {
+            continue;
+        }
+
+        if goal(&state) {
+            let mut path = vec![state];
+            while let Some(parent) = store.parent(path.last().unwrap()) {
+                path.push(parent.clone());
+            }
+            path.reverse();
+            return Some(SearchResult { cost, path });
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if store.best(&next).is_some_and(|best| best <= next_cost) {
+                continue;
+            }
+            store.record(next.clone(), next_cost, Some(state.clone()));
+            heap.push(Entry {
+                priority: next_cost + heuristic(&next),
+                cost: next_cost,
+                state: next,
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Pos = (usize, usize);
+
+    // Cost of entering each cell, 0 being a wall: the shortcut at the top is blocked
+    const GRID: [[u32; 3]; 3] = [[1, 0, 1], [1, 9, 1], [1, 1, 1]];
+
+    fn successors(grid: &[[u32; 3]; 3]) -> impl FnMut(&Pos) -> Vec<(Pos, u32)> + '_ {
+        move |&(i, j)| {
+            let mut next = Vec::new();
+            for (di, dj) in [(-1, 0), (0, -1), (0, 1), (1, 0)] {
+                let (i, j) = (i.wrapping_add_signed(di), j.wrapping_add_signed(dj));
+                if let Some(&cost) = grid.get(i).and_then(|row| row.get(j)) {
+                    if cost > 0 {
+                        next.push(((i, j), cost));
+                    }
+                }
+            }
+            next
+        }
+    }
+
+    #[test]
+    fn stores() {
+        let goal = |&s: &Pos| s == (0, 2);
+        let heuristic = |&(i, j): &Pos| (i + 2usize.abs_diff(j)) as u32;
+
+        let hashed = astar((0, 0), successors(&GRID), goal, heuristic).unwrap();
+        assert_eq!(hashed.cost, 6);
+        assert_eq!(hashed.path.len(), 7);
+
+        let mut store = DenseStore::new(9, |&(i, j): &Pos| i * 3 + j);
+        let dense =
+            astar_with_store(&mut store, (0, 0), successors(&GRID), goal, heuristic).unwrap();
+        assert_eq!(dense, hashed);
+
+        let mut store = HashStore::new();
+        let plain = dijkstra_with_store(&mut store, (0, 0), successors(&GRID), goal).unwrap();
+        assert_eq!(plain.cost, 6);
+    }
+
+    #[test]
+    fn unreachable() {
+        let walled = [[1, 0, 1], [1, 0, 1], [1, 0, 1]];
+        assert_eq!(
+            dijkstra((0, 0), successors(&walled), |&s| s == (0, 2)),
+            None
+        );
+        let mut store = DenseStore::new(9, |&(i, j): &Pos| i * 3 + j);
+        assert_eq!(
+            dijkstra_with_store(&mut store, (0, 0), successors(&walled), |&s| s == (0, 2)),
+            None
+        );
+    }
+}
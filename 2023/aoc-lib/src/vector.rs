@@ -0,0 +1,252 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use num::{rational::Ratio, Integer, Zero};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+// Absolute value that also works for unsigned types
+fn abs<T>(x: T) -> T
+where
+    T: Zero,
+    T: PartialOrd,
+    T: Sub<Output = T>,
+{
+    if x < T::zero() {
+        T::zero() - x
+    } else {
+        x
+    }
+}
+
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+macro_rules! implement_vector {
+    ($type:ident { $($field:ident),* }) => {
+        impl<T> $type<T> {
+            pub fn new($($field: T),*) -> Self {
+                Self { $($field),* }
+            }
+
+            pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> $type<U> {
+                $type { $($field: f(self.$field)),* }
+            }
+        }
+
+        impl<T: Copy + Zero + PartialOrd + Sub<Output = T>> $type<T> {
+            pub fn dot(self, other: Self) -> T
+            where
+                T: Mul<Output = T>,
+            {
+                T::zero() $(+ self.$field * other.$field)*
+            }
+
+            /// Sum of the absolute values of the components
+            pub fn manhattan(self) -> T {
+                T::zero() $(+ abs(self.$field))*
+            }
+
+            /// Component-wise minimum
+            pub fn min(self, other: Self) -> Self {
+                Self { $($field: min(self.$field, other.$field)),* }
+            }
+            /// Component-wise maximum
+            pub fn max(self, other: Self) -> Self {
+                Self { $($field: max(self.$field, other.$field)),* }
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $type<T> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+        impl<T: Sub<Output = T>> Sub for $type<T> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+        impl<T: Neg<Output = T>> Neg for $type<T> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $type<T> {
+            type Output = Self;
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+        impl<T: AddAssign> AddAssign for $type<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+        impl<T: SubAssign> SubAssign for $type<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+
+implement_vector!(Vec2 { x, y });
+implement_vector!(Vec3 { x, y, z });
+
+impl<T: Copy + Mul<Output = T> + Sub<Output = T>> Vec2<T> {
+    /// Z component of the 3D cross product, positive if `other` is counter-clockwise from `self`
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: Copy + Mul<Output = T> + Sub<Output = T>> Vec3<T> {
+    pub fn cross(self, other: Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+    pub fn xy(self) -> Vec2<T> {
+        Vec2::new(self.x, self.y)
+    }
+}
+
+impl<T> From<[T; 2]> for Vec2<T> {
+    fn from([x, y]: [T; 2]) -> Self {
+        Self { x, y }
+    }
+}
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+impl<T> From<[T; 3]> for Vec3<T> {
+    fn from([x, y, z]: [T; 3]) -> Self {
+        Self { x, y, z }
+    }
+}
+impl<T> From<(T, T, T)> for Vec3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+/// Line going through `origin` along `direction`, or a ray starting at `origin`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Line2<T> {
+    pub origin: Vec2<T>,
+    pub direction: Vec2<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Intersection<T: Clone + Integer> {
+    /// Single intersection at `origin + t * direction` on the first line, `u` on the second
+    Point {
+        point: Vec2<Ratio<T>>,
+        t: Ratio<T>,
+        u: Ratio<T>,
+    },
+    Parallel,
+    Coincident,
+}
+
+impl<T> Line2<T>
+where
+    T: Copy,
+    T: Integer,
+{
+    pub fn new(origin: Vec2<T>, direction: Vec2<T>) -> Self {
+        Self { origin, direction }
+    }
+
+    /// Exact intersection of both lines
+    pub fn intersect(&self, other: &Self) -> Intersection<T> {
+        let denominator = self.direction.cross(other.direction);
+        let offset = other.origin - self.origin;
+        if denominator.is_zero() {
+            return if offset.cross(self.direction).is_zero() {
+                Intersection::Coincident
+            } else {
+                Intersection::Parallel
+            };
+        }
+        let t = Ratio::new(offset.cross(other.direction), denominator);
+        let u = Ratio::new(offset.cross(self.direction), denominator);
+        let point = self.origin.map(Ratio::from_integer) + self.direction.map(|d| t * d);
+        Intersection::Point { point, t, u }
+    }
+
+    /// Intersection point of both rays, if they cross at a single point
+    pub fn ray_intersection(&self, other: &Self) -> Option<Vec2<Ratio<T>>> {
+        match self.intersect(other) {
+            Intersection::Point { point, t, u } if t >= Ratio::zero() && u >= Ratio::zero() => {
+                Some(point)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vectors() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::from([4, -5, 6]);
+        assert_eq!(a + b, Vec3::new(5, -3, 9));
+        assert_eq!(a.dot(b), 12);
+        assert_eq!(a.cross(b), Vec3::new(27, 6, -13));
+        assert_eq!((a - b).manhattan(), 13);
+        assert_eq!(a.min(b), Vec3::new(1, -5, 3));
+        assert_eq!(Vec2::new(3_u16, 1).max(Vec2::new(2, 7)), Vec2::new(3, 7));
+    }
+
+    #[test]
+    fn intersections() {
+        let a = Line2::new(Vec2::new(19, 13), Vec2::new(-2, 1));
+        let b = Line2::new(Vec2::new(18, 19), Vec2::new(-1, -1));
+        let expected = Vec2::new(Ratio::new(43, 3), Ratio::new(46, 3));
+        assert_eq!(a.ray_intersection(&b), Some(expected));
+
+        let c = Line2::new(Vec2::new(20, 25), Vec2::new(-2, -2));
+        assert_eq!(b.intersect(&c), Intersection::Parallel);
+        let d = Line2::new(Vec2::new(16, 17), Vec2::new(3, 3));
+        assert_eq!(b.intersect(&d), Intersection::Coincident);
+        assert_eq!(
+            b.ray_intersection(&Line2::new(Vec2::new(20, 19), -a.direction)),
+            None
+        );
+    }
+}
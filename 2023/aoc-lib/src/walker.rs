@@ -0,0 +1,156 @@
+use crate::{geometry::Polygon, Direction, Grid};
+
+/// Position and heading moving on a grid, optionally recording its path
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Walker {
+    pub pos: (isize, isize),
+    pub dir: Direction,
+    bounds: Option<(usize, usize)>,
+    vertices: Option<Vec<(isize, isize)>>,
+    cells: Option<Vec<(isize, isize)>>,
+}
+
+impl Walker {
+    pub fn new(pos: (isize, isize), dir: Direction) -> Self {
+        Self {
+            pos,
+            dir,
+            bounds: None,
+            vertices: None,
+            cells: None,
+        }
+    }
+
+    /// Keep the walker within `rows x cols`
+    pub fn bounded(mut self, rows: usize, cols: usize) -> Self {
+        self.bounds = Some((rows, cols));
+        self
+    }
+    /// Keep the walker within the grid
+    pub fn bounded_by<T>(self, grid: &Grid<T>) -> Self {
+        self.bounded(grid.rows(), grid.cols())
+    }
+    /// Record the position at the end of every move
+    pub fn record_vertices(mut self) -> Self {
+        self.vertices = Some(vec![self.pos]);
+        self
+    }
+    /// Record every cell walked on
+    pub fn record_cells(mut self) -> Self {
+        self.cells = Some(vec![self.pos]);
+        self
+    }
+
+    pub fn in_bounds(&self, (i, j): (isize, isize)) -> bool {
+        self.bounds.is_none_or(|(rows, cols)| {
+            (0..rows as isize).contains(&i) && (0..cols as isize).contains(&j)
+        })
+    }
+
+    /// Position `n` cells ahead, without moving
+    pub fn ahead(&self, n: isize) -> (isize, isize) {
+        let (di, dj) = self.dir.step(0, 0);
+        (self.pos.0 + di * n, self.pos.1 + dj * n)
+    }
+
+    /// Move `n` cells forward, or backward if `n` is negative.
+    ///
+    /// Returns false if the move was cut short at the edge of the bounds.
+    pub fn forward(&mut self, n: isize) -> bool {
+        let mut n = n;
+        let mut complete = true;
+        if self.bounds.is_some() && !self.in_bounds(self.ahead(n)) {
+            // Walk as far as possible without leaving the bounds
+            let step = n.signum();
+            let mut k = 0;
+            while self.in_bounds(self.ahead(k + step)) {
+                k += step;
+            }
+            n = k;
+            complete = false;
+        }
+
+        if let Some(cells) = &mut self.cells {
+            let (di, dj) = self.dir.step(0, 0);
+            let step = n.signum();
+            cells.extend(
+                (1..=n.abs()).map(|k| (self.pos.0 + di * step * k, self.pos.1 + dj * step * k)),
+            );
+        }
+        self.pos = self.ahead(n);
+        if n != 0 {
+            if let Some(vertices) = &mut self.vertices {
+                vertices.push(self.pos);
+            }
+        }
+        complete
+    }
+    /// Move one cell forward, returns false if blocked by the bounds
+    pub fn step(&mut self) -> bool {
+        self.forward(1)
+    }
+
+    pub fn turn_left(&mut self) -> &mut Self {
+        self.dir = self.dir.rotate_left();
+        self
+    }
+    pub fn turn_right(&mut self) -> &mut Self {
+        self.dir = self.dir.rotate_right();
+        self
+    }
+    /// Turn around
+    pub fn reflect(&mut self) -> &mut Self {
+        self.dir = self.dir.reflect();
+        self
+    }
+
+    /// Recorded positions at the end of every move, starting with the initial one
+    pub fn vertices(&self) -> &[(isize, isize)] {
+        self.vertices.as_deref().unwrap_or_default()
+    }
+    /// Recorded cells, in order and possibly repeated
+    pub fn cells(&self) -> &[(isize, isize)] {
+        self.cells.as_deref().unwrap_or_default()
+    }
+
+    /// Polygon going through the recorded vertices
+    pub fn to_polygon(&self) -> Polygon {
+        let mut vertices = self
+            .vertices()
+            .iter()
+            .map(|&(i, j)| (i as i64, j as i64))
+            .collect::<Vec<_>>();
+        if vertices.len() > 1 && vertices.first() == vertices.last() {
+            vertices.pop();
+        }
+        Polygon::new(vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walk() {
+        let mut walker = Walker::new((0, 0), Direction::East)
+            .record_vertices()
+            .record_cells();
+        walker.forward(3);
+        walker.turn_right().forward(2);
+        walker.turn_right().forward(3);
+        walker.turn_right().forward(2);
+        assert_eq!(walker.pos, (0, 0));
+        assert_eq!(walker.vertices(), &[(0, 0), (0, 3), (2, 3), (2, 0), (0, 0)]);
+        assert_eq!(walker.cells().len(), 11);
+        assert_eq!(walker.to_polygon().lattice_points(), 12);
+
+        let mut walker = Walker::new((1, 1), Direction::North).bounded(3, 3);
+        assert!(!walker.forward(5));
+        assert_eq!(walker.pos, (0, 1));
+        walker.reflect();
+        assert!(walker.forward(2));
+        assert!(!walker.step());
+        assert_eq!(walker.pos, (2, 1));
+    }
+}
@@ -1,7 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
-use aoc_lib::ascii::{AsAsciiStr, AsciiChar, AsciiStr};
+use aoc_lib::{
+    ascii::{AsAsciiStr, AsciiChar, AsciiStr},
+    Direction8,
+};
 
 fn main() {
     let filename = std::env::args().nth(1).unwrap_or("input.txt".to_string());
@@ -26,31 +29,28 @@ fn solve(input: &AsciiStr) -> Result<()> {
                 }
             } else {
                 if let Some(number_start) = number_start {
-                    numbers.insert((i as i32, number_start as i32), &line[number_start..j]);
+                    numbers.insert((i as isize, number_start as isize), &line[number_start..j]);
                 }
                 number_start = None;
 
                 if *ch != AsciiChar::Dot {
-                    symbols.insert((i as i32, j as i32));
+                    symbols.insert((i as isize, j as isize));
                 }
             }
         }
         if let Some(number_start) = number_start {
-            numbers.insert((i as i32, number_start as i32), &line[number_start..]);
+            numbers.insert((i as isize, number_start as isize), &line[number_start..]);
         }
     }
 
     let mut s = 0;
 
     for ((i, j), n) in numbers {
-        let mut has_neighbor = false;
-        for i in i - 1..=i + 1 {
-            for j in j - 1..=j + n.len() as i32 {
-                if symbols.contains(&(i, j)) {
-                    has_neighbor = true;
-                }
-            }
-        }
+        let has_neighbor = (j..j + n.len() as isize).any(|j| {
+            Direction8::ALL
+                .iter()
+                .any(|dir| symbols.contains(&dir.step(i, j)))
+        });
 
         if has_neighbor {
             let n: i32 = n.as_str().parse()?;
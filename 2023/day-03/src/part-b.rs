@@ -1,7 +1,10 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::Result;
-use aoc_lib::ascii::{AsAsciiStr, AsciiChar, AsciiStr};
+use aoc_lib::{
+    ascii::{AsAsciiStr, AsciiChar, AsciiStr},
+    Direction8,
+};
 
 fn main() {
     let filename = std::env::args().nth(1).unwrap_or("input.txt".to_string());
@@ -26,30 +29,31 @@ fn solve(input: &AsciiStr) -> Result<()> {
                 }
             } else {
                 if let Some(number_start) = number_start {
-                    numbers.insert((i as i32, number_start as i32), &line[number_start..j]);
+                    numbers.insert((i as isize, number_start as isize), &line[number_start..j]);
                 }
                 number_start = None;
 
                 if *ch == AsciiChar::Asterisk {
-                    gears.insert((i as i32, j as i32), (0, 1));
+                    gears.insert((i as isize, j as isize), (0, 1));
                 }
             }
         }
         if let Some(number_start) = number_start {
-            numbers.insert((i as i32, number_start as i32), &line[number_start..]);
+            numbers.insert((i as isize, number_start as isize), &line[number_start..]);
         }
     }
 
     for ((i, j), n) in numbers {
-        let len = n.len() as i32;
+        // A gear can be adjacent to several digits of the same number
+        let neighbors = (j..j + n.len() as isize)
+            .flat_map(|j| Direction8::ALL.map(|dir| dir.step(i, j)))
+            .collect::<HashSet<_>>();
 
         let n: i32 = n.as_str().parse()?;
-        for i in i - 1..=i + 1 {
-            for j in j - 1..=j + len {
-                if let Some(x) = gears.get_mut(&(i, j)) {
-                    x.0 += 1;
-                    x.1 *= n;
-                }
+        for pos in neighbors {
+            if let Some(x) = gears.get_mut(&pos) {
+                x.0 += 1;
+                x.1 *= n;
             }
         }
     }
@@ -1,7 +1,7 @@
 use anyhow::Result;
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
-    CollectExact, SplitExact, SplitWhitespace,
+    count_integer_solutions_quadratic, CollectExact, SplitExact, SplitWhitespace,
 };
 
 fn main() {
@@ -29,14 +29,8 @@ fn solve(input: &AsciiStr) -> Result<()> {
         distance = distance * 10_u64.pow(d.len() as u32) + d.as_str().parse::<u64>()?;
     }
 
-    let mut count = 0;
-
-    for speed in 0..time {
-        let d = speed * (time - speed);
-        if d > distance {
-            count += 1;
-        }
-    }
+    // hold * (time - hold) > distance
+    let count = count_integer_solutions_quadratic::<u64>(-1, time, -i128::from(distance))?;
 
     println!("Part B:\n{count}");
     Ok(())
@@ -2,8 +2,10 @@ use std::{collections::HashMap, time::Instant};
 
 use anyhow::{anyhow, Result};
 use aoc_lib::{
+    align_cycles,
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
-    lcm, SplitExact,
+    cycle::find_cycle_memo,
+    CycleHits, SplitExact,
 };
 
 fn main() {
@@ -41,23 +43,29 @@ fn solve(input: &AsciiStr) -> Result<()> {
         }
     }
 
-    let mut combined = 1;
-
-    for mut current in currents {
-        let mut i = 0;
-        while current[2] != AsciiChar::Z {
+    // Ghosts do not necessarily loop back to their start, nor hit a single end node per loop
+    let mut cycles = Vec::new();
+    for current in currents {
+        let mut cycle = find_cycle_memo((current, 0), |&(current, i)| {
             let node = graph[current];
-            let dir = directions[i % directions.len()];
-            if dir == AsciiChar::L {
-                current = node.0;
+            let next = if directions[i] == AsciiChar::L {
+                node.0
             } else {
-                current = node.1;
-            }
-            i += 1;
-        }
-
-        combined = lcm(combined, i as u64);
+                node.1
+            };
+            (next, (i + 1) % directions.len())
+        });
+        let hits = (0..cycle.start + cycle.period)
+            .filter(|&t| cycle.nth_state(t).0[2] == AsciiChar::Z)
+            .map(|t| t as u64)
+            .collect();
+        cycles.push(CycleHits {
+            offset: cycle.start as u64,
+            period: cycle.period as u64,
+            hits,
+        });
     }
+    let combined = align_cycles(&cycles).ok_or(anyhow!("ghosts never align"))?;
 
     let elapsed = timer.elapsed();
     println!("Part B ({elapsed:?}):\n{combined}");
@@ -1,13 +1,12 @@
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiStr},
+    poly::extrapolate_backward,
     SplitWhitespace,
 };
 
-mod utils;
-
 fn main() {
     let filename = std::env::args().nth(1).unwrap_or("input.txt".to_string());
     let content = std::fs::read(filename.as_ref() as &str).expect("Could not read input file");
@@ -21,10 +20,13 @@ fn solve(input: &AsciiStr) -> Result<()> {
     let timer = Instant::now();
 
     let mut s = 0;
-    let mut solver = utils::Solver::default();
 
     for line in input.lines() {
-        s += solver.extrapolate(line.split_whitespace().rev().map(|s| s.as_str().parse()))?;
+        let values = line
+            .split_whitespace()
+            .map(|s| s.as_str().parse())
+            .collect::<Result<Vec<i64>, _>>()?;
+        s += extrapolate_backward(&values).ok_or(anyhow!("Extrapolation overflowed"))?;
     }
     let elapsed = timer.elapsed();
     println!("Part B ({elapsed:?}):\n{s}");
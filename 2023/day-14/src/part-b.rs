@@ -1,8 +1,9 @@
-use std::{collections::HashMap, time::Instant};
+use std::time::Instant;
 
 use anyhow::Result;
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
+    cycle::try_find_cycle_memo,
     Factor, Grid,
 };
 
@@ -15,7 +16,7 @@ fn main() {
     solve(content).expect("Could not solve part B")
 }
 
-fn cycle(grid: &mut Grid<AsciiChar>) -> Result<()> {
+fn spin(grid: &mut Grid<AsciiChar>) -> Result<()> {
     for (di, dj) in [(-1_isize, 0_isize), (0, -1), (1, 0), (0, 1)] {
         let mut fixed = false;
         while !fixed {
@@ -46,27 +47,14 @@ fn solve(input: &AsciiStr) -> Result<()> {
         grid.add_row(line.as_slice().iter().copied())?;
     }
 
-    let mut grid2time = HashMap::new();
-    let mut time2grid = HashMap::new();
-
-    let mut t = 0_usize;
-    let cycle_start;
-    loop {
-        match grid2time.entry(grid.clone()) {
-            std::collections::hash_map::Entry::Occupied(entry) => {
-                cycle_start = *entry.get();
-                break;
-            }
-            std::collections::hash_map::Entry::Vacant(entry) => {
-                entry.insert(t);
-                time2grid.insert(t, grid.clone());
-            }
-        }
-        cycle(&mut grid)?;
-        t += 1;
-    }
-    let cycle_len = t - cycle_start;
-    let grid = &time2grid[&(cycle_start + (1000000000_usize - cycle_start) % cycle_len)];
+    let mut cycle = try_find_cycle_memo(grid, |grid| -> Result<_> {
+        let mut grid = grid.clone();
+        spin(&mut grid)?;
+        Ok(grid)
+    })?;
+    let cycle_start = cycle.start;
+    let t = cycle.start + cycle.period;
+    let grid = &cycle.try_nth_state(1000000000)?;
 
     let s = grid
         .iter()
@@ -1,8 +1,6 @@
-use std::collections::BinaryHeap;
-
 use anyhow::{anyhow, Result};
 
-use aoc_lib::{ascii::AsciiStr, CharExt, Direction, Factor, Grid};
+use aoc_lib::{ascii::AsciiStr, BucketQueue, CharExt, Direction, Factor, Grid, KeyedQueue};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Node {
@@ -12,21 +10,13 @@ pub struct Node {
     pub cost: u32,
 }
 
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        other.cost.partial_cmp(&self.cost)
-    }
-}
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.cost.cmp(&self.cost)
-    }
-}
+// Costs only grow by a few steps of heat loss, which suits a bucket queue
+type NodeQueue = KeyedQueue<BucketQueue<u32, Node>, fn(&Node) -> u32>;
 
 pub struct Solver {
     pub map: Grid<u32>,
     pub heatloss: Grid<[u32; 4]>,
-    pub heap: BinaryHeap<Node>,
+    pub heap: NodeQueue,
 }
 
 impl Solver {
@@ -37,7 +27,7 @@ impl Solver {
         }
 
         let heatloss = map.map(|_| [u32::MAX; 4]);
-        let heap = BinaryHeap::new();
+        let heap: NodeQueue = KeyedQueue::new(BucketQueue::new(), |node| node.cost);
 
         Ok(Self {
             map,
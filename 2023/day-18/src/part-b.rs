@@ -3,6 +3,7 @@ use std::time::Instant;
 use anyhow::{bail, Result};
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
+    geometry::Polygon,
     Direction, SplitExactWhitespace,
 };
 
@@ -18,13 +19,7 @@ fn main() {
 fn solve(input: &AsciiStr) -> Result<()> {
     let timer = Instant::now();
 
-    let mut area2 = 0_i64;
-    let mut straight = 0_i64;
-    let (mut direct, mut indirect) = (0_i64, 0_i64);
-    let (mut i, mut j) = (0_i64, 0_i64);
-    let mut last_dir: Option<Direction> = None;
-    let mut first_dir = Direction::North;
-
+    let mut steps = Vec::new();
     for line in input.lines() {
         let [_, _, spec] = line.split_exact_whitespace()?;
         let n = &spec[2..spec.len() - 2];
@@ -37,41 +32,10 @@ fn solve(input: &AsciiStr) -> Result<()> {
             AsciiChar::_3 => Direction::North,
             _ => bail!("{dir} is not a valid direction"),
         };
-
-        let (ni, nj) = match dir {
-            Direction::North => (i - n, j),
-            Direction::South => (i + n, j),
-            Direction::East => (i, j + n),
-            Direction::West => (i, j - n),
-        };
-
-        area2 += i * nj - ni * j;
-
-        if let Some(last_dir) = last_dir {
-            if last_dir.rotate_right() == dir {
-                direct += 1;
-            } else {
-                indirect += 1;
-            }
-        } else {
-            first_dir = dir;
-        }
-
-        straight += n - 1;
-        last_dir = Some(dir);
-        (i, j) = (ni, nj);
-    }
-
-    if let Some(last_dir) = last_dir {
-        if last_dir.rotate_right() == first_dir {
-            direct += 1;
-        } else {
-            indirect += 1;
-        }
+        steps.push((dir, n));
     }
 
-    let area =
-        (2 * area2.abs() + 2 * straight + direct.min(indirect) + 3 * direct.max(indirect)) / 4;
+    let area = Polygon::from_steps(steps).lattice_points();
     let elapsed = timer.elapsed();
     println!("Part B ({elapsed:?}):\n{area}");
     Ok(())
@@ -89,6 +89,10 @@ fn solve(input: &AsciiStr) -> Result<()> {
         };
     }
 
+    if let Some(filename) = std::env::args().nth(2) {
+        workflow::write_dot(&workflows, &id_map, filename)?;
+    }
+
     let mut s = 0_u64;
     for line in lines {
         let part = Part(
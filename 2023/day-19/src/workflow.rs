@@ -1,6 +1,12 @@
 #![allow(unused)]
 
-use std::ops::{Add, Range, Sub};
+use std::{
+    fmt::Display,
+    ops::{Add, Range, Sub},
+    path::Path,
+};
+
+use aoc_lib::{ascii::AsciiStr, graph::Dot, IntegerMap};
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Part<T = u16>(pub [T; 4]);
@@ -65,6 +71,17 @@ pub struct WorkflowStep {
     pub action: WorkflowAction,
 }
 
+impl Display for WorkflowStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let category = ["x", "m", "a", "s"][self.category as usize];
+        let comparison = match self.comparison {
+            WorkflowComparison::LessThan => "<",
+            WorkflowComparison::GreaterThan => ">",
+        };
+        f.write_fmt(format_args!("{category}{comparison}{}", self.value))
+    }
+}
+
 impl WorkflowStep {
     pub fn check(&self, part: &Part) -> bool {
         let part_value = part.0[self.category as usize];
@@ -92,3 +109,44 @@ impl Workflow {
         self.default_action
     }
 }
+
+/// Write the workflows in DOT format, with an edge for every decision
+pub fn write_dot(
+    workflows: &[Workflow],
+    id_map: &IntegerMap<&AsciiStr, ID>,
+    path: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    // Accept and Reject are extra nodes after the workflows
+    let accept = workflows.len();
+    let reject = accept + 1;
+    let node = |action| match action {
+        WorkflowAction::Accept => accept,
+        WorkflowAction::Reject => reject,
+        WorkflowAction::Workflow(id) => id as usize,
+    };
+
+    let mut decisions = vec![Vec::new(); workflows.len() + 2];
+    for (id, workflow) in workflows.iter().enumerate() {
+        for step in &workflow.steps {
+            decisions[id].push((node(step.action), step.to_string()));
+        }
+        decisions[id].push((node(workflow.default_action), String::new()));
+    }
+
+    let dot = Dot::directed(&decisions)
+        .name("workflows")
+        .node_attributes(move |id| {
+            if id == accept {
+                vec![("label", "A".to_string()), ("color", "green".to_string())]
+            } else if id == reject {
+                vec![("label", "R".to_string()), ("color", "red".to_string())]
+            } else {
+                vec![
+                    ("label", id_map.value(id as ID).to_string()),
+                    ("shape", "box".to_string()),
+                ]
+            }
+        })
+        .edge_attributes(|_, _, condition| vec![("label", condition.clone())]);
+    dot.write_to(path)
+}
@@ -24,6 +24,9 @@ fn solve(input: &AsciiStr) -> Result<()> {
 
     let mut relays = Relays::from_input(input)?;
     // println!("{relays}");
+    if let Some(filename) = std::env::args().nth(2) {
+        relays.write_dot(filename)?;
+    }
 
     let (mut low, mut high) = (0_u64, 0_u64);
     for _ in 0..1000 {
@@ -26,6 +26,9 @@ fn solve(input: &AsciiStr) -> Result<()> {
     let timer = Instant::now();
 
     let mut relays = Relays::from_input(input)?;
+    if let Some(filename) = std::env::args().nth(2) {
+        relays.write_dot(filename)?;
+    }
     let rx = relays.id("rx".as_ascii_str()?);
     relays.last = relays[rx].state.trailing_ones() as u8;
 
@@ -2,11 +2,13 @@ use std::{
     collections::{hash_map::Entry, HashMap, VecDeque},
     fmt::Display,
     ops::{Index, IndexMut},
+    path::Path,
 };
 
 use anyhow::Result;
 use aoc_lib::{
     ascii::{AsciiChar, AsciiStr},
+    graph::{Adjacency, Dot},
     SplitExact as _,
 };
 use arrayvec::ArrayVec;
@@ -56,6 +58,20 @@ impl<'a> Display for Relays<'a> {
     }
 }
 
+impl<'a> Adjacency for Relays<'a> {
+    type Weight = ();
+
+    fn node_count(&self) -> usize {
+        self.relays.len()
+    }
+    fn neighbors(&self, node: usize) -> impl Iterator<Item = (usize, &())> + '_ {
+        self.relays[node]
+            .targets
+            .iter()
+            .map(|&target| (target as usize, &()))
+    }
+}
+
 impl<'a> Relays<'a> {
     pub fn new() -> Self {
         Self::with_capacity(16)
@@ -132,6 +148,30 @@ impl<'a> Relays<'a> {
         Ok(relays)
     }
 
+    /// Write the circuit in DOT format: flip-flops are boxes, conjunctions are diamonds
+    pub fn write_dot(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        Dot::directed(self)
+            .name("relays")
+            .node_attributes(|id| {
+                let relay = &self.relays[id];
+                let (shape, color) = if id == self.broadcast as usize {
+                    ("doublecircle", "black")
+                } else if relay.state < 0 {
+                    ("box", "blue")
+                } else if relay.targets.is_empty() {
+                    ("plaintext", "black")
+                } else {
+                    ("diamond", "red")
+                };
+                vec![
+                    ("label", relay.name.to_string()),
+                    ("shape", shape.to_string()),
+                    ("color", color.to_string()),
+                ]
+            })
+            .write_to(path)
+    }
+
     pub fn trigger(&mut self, id: u8, high: bool) -> RelayCount {
         let mut count = RelayCount::default();
         self.queue.clear();
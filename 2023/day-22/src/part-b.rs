@@ -1,8 +1,9 @@
-use std::{collections::HashSet, time::Instant};
+use std::time::Instant;
 
 use anyhow::Result;
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
+    graph::DominatorTree,
     Factor, SplitExact,
 };
 
@@ -84,9 +85,6 @@ fn solve(input: &AsciiStr) -> Result<()> {
     }
     bricks.sort_by_key(|brick| brick.pos);
 
-    let mut under = (0..bricks.len())
-        .map(|_| Vec::<u16>::new())
-        .collect::<Vec<_>>();
     let mut above = (0..bricks.len())
         .map(|_| Vec::<u16>::new())
         .collect::<Vec<_>>();
@@ -108,11 +106,9 @@ fn solve(input: &AsciiStr) -> Result<()> {
 
         let mut last = u16::MAX;
         let mut pos = brick_flat.pos as u8;
-        let mut below = Vec::new();
         for _ in 0..brick_flat.len {
             let b = top_brick[pos as usize];
             if b != last && top_height[pos as usize] == max_height {
-                below.push(b);
                 above[b as usize].push(i as u16);
             }
             top_height[pos as usize] = max_height + zlen as u16;
@@ -120,61 +116,21 @@ fn solve(input: &AsciiStr) -> Result<()> {
             pos += brick_flat.stride;
             last = b;
         }
-
-        under[i] = below;
     }
 
-    let mut sorted = (1..bricks.len() as u16).collect::<Vec<_>>();
-    sorted.sort_by_key(|&i| bricks[i as usize].pos);
-
-    let mut chained = (0..bricks.len())
-        .map(|_| HashSet::<u16>::new())
-        .collect::<Vec<_>>();
-    let mut partially_chained = (0..bricks.len())
-        .map(|_| HashSet::<u16>::new())
+    // Brick 0 is the ground: a brick falls with `i` iff `i` dominates it in the support graph
+    let supports = above
+        .iter()
+        .map(|bricks| bricks.iter().map(|&j| j as usize).collect::<Vec<_>>())
         .collect::<Vec<_>>();
+    let tree = DominatorTree::new(&supports, 0);
 
     let mut n = 0_usize;
     let mut s = 0_usize;
-
-    for &i in sorted.iter().rev() {
-        let mut chain = HashSet::new();
-        let mut partial_chain = HashSet::new();
-
-        for &j in &above[i as usize] {
-            let set = if under[j as usize] == [i] {
-                &mut chain
-            } else {
-                &mut partial_chain
-            };
-            set.insert(j);
-            set.extend(&chained[j as usize]);
-            partial_chain.extend(&partially_chained[j as usize]);
-            partial_chain.insert(j);
-        }
-
-        let mut stable = false;
-        while !stable {
-            stable = true;
-            partial_chain.retain(|&j| {
-                if under[j as usize]
-                    .iter()
-                    .all(|k| *k == i || chain.contains(k))
-                {
-                    stable = false;
-                    chain.insert(j);
-                    false
-                } else {
-                    true
-                }
-            });
-        }
-
-        n += chain.is_empty() as usize;
-        s += chain.len();
-
-        chained[i as usize] = chain;
-        partially_chained[i as usize] = partial_chain;
+    for i in 1..bricks.len() {
+        let falling = tree.subtree_size(i) - 1;
+        n += (falling == 0) as usize;
+        s += falling;
     }
 
     let elapsed = timer.elapsed();
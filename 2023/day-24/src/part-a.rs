@@ -3,7 +3,8 @@ use std::time::Instant;
 use anyhow::Result;
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
-    Factor, SplitExact,
+    linalg::Rational,
+    Factor, Line2, SplitExact, Vec3,
 };
 
 fn main() {
@@ -15,22 +16,18 @@ fn main() {
     solve(content).expect("Could not solve part A")
 }
 
-// i64 overflows, but i128, f32 and f64 work
-pub type Number = f32;
+// Intersections are computed exactly: i64 would overflow
+pub type Number = i128;
 
 pub struct Hailstone {
-    pub x: Number,
-    pub y: Number,
-    pub z: Number,
-    pub dx: Number,
-    pub dy: Number,
-    pub dz: Number,
+    pub pos: Vec3<Number>,
+    pub vel: Vec3<Number>,
 }
 
-const WINDOW_MIN: Number = 200000000000000.0;
-const WINDOW_MAX: Number = 400000000000000.0;
-// const WINDOW_MIN: Number = 7.0;
-// const WINDOW_MAX: Number = 27.0;
+const WINDOW_MIN: Number = 200000000000000;
+const WINDOW_MAX: Number = 400000000000000;
+// const WINDOW_MIN: Number = 7;
+// const WINDOW_MAX: Number = 27;
 
 fn solve(input: &AsciiStr) -> Result<()> {
     let timer = Instant::now();
@@ -38,7 +35,7 @@ fn solve(input: &AsciiStr) -> Result<()> {
     let mut hailstones = Vec::new();
 
     for line in input.lines() {
-        let [[x, y, z], [dx, dy, dz]]: [[Number; 3]; 2] = line
+        let [pos, vel]: [[Number; 3]; 2] = line
             .split_exact(AsciiChar::At)?
             .map(|coords| {
                 coords
@@ -49,50 +46,24 @@ fn solve(input: &AsciiStr) -> Result<()> {
             })
             .factor()?;
         hailstones.push(Hailstone {
-            x,
-            y,
-            z,
-            dx,
-            dy,
-            dz,
+            pos: pos.into(),
+            vel: vel.into(),
         });
     }
 
+    let window = Rational::from_integer(WINDOW_MIN)..=Rational::from_integer(WINDOW_MAX);
     let mut n = 0_usize;
     for (i, h0) in hailstones.iter().enumerate() {
+        let l0 = Line2::new(h0.pos.xy(), h0.vel.xy());
         for h1 in &hailstones[..i] {
-            let mut c = h0.dx * h1.dy - h1.dx * h0.dy;
-            if c == 0.0 {
-                continue;
-            }
-
-            let mut ac = (h1.x - h0.x) * h1.dy + (h0.y - h1.y) * h1.dx;
-            let mut bc = (h1.x - h0.x) * h0.dy + (h0.y - h1.y) * h0.dx;
+            let l1 = Line2::new(h1.pos.xy(), h1.vel.xy());
 
-            // Ensures a and c are positive if and only if intersection is in the future
-            if c < 0.0 {
-                ac = -ac;
-                bc = -bc;
-                c = -c;
+            // Intersection must be in the future of both hailstones, and inside the test zone
+            if let Some(p) = l0.ray_intersection(&l1) {
+                if window.contains(&p.x) && window.contains(&p.y) {
+                    n += 1;
+                }
             }
-
-            // Intersection must be in the future of both hailstones
-            if ac < 0.0 || bc < 0.0 {
-                continue;
-            }
-
-            let xc = c * h0.x + ac * h0.dx;
-            let yc = c * h0.y + ac * h0.dy;
-
-            // intersection is outside of test zone
-            if xc < WINDOW_MIN * c || xc > WINDOW_MAX * c {
-                continue;
-            }
-            if yc < WINDOW_MIN * c || yc > WINDOW_MAX * c {
-                continue;
-            }
-
-            n += 1;
         }
     }
     let elapsed = timer.elapsed();
@@ -1,11 +1,12 @@
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
 use aoc_lib::{
     ascii::{AsAsciiStr, AsciiChar, AsciiStr},
-    Factor, SplitExact,
+    linalg::{least_squares, Matrix, Rational},
+    Factor, SplitExact, Vec3,
 };
-use ndarray_linalg::LeastSquaresSvdInPlace;
+use num::BigInt;
 
 fn main() {
     let filename = std::env::args().nth(1).unwrap_or("input.txt".to_string());
@@ -16,16 +17,16 @@ fn main() {
     solve(content).expect("Could not solve part B")
 }
 
-// i64 overflows, but i128, f32 and f64 work
-pub type Number = f64;
+// Coordinates are below 2⁵⁰ and velocities below 2¹⁰ in magnitude, so the cross products
+// (below 2⁶¹) fit in i64 but leave little room: i128 is used for the input instead.
+// The normal equations of the least-squares system have entries up to 2¹¹⁴ for 300
+// hailstones, and eliminating them multiplies those entries together, so the solving
+// is done with BigInt rationals: Rational<i128> overflows on real inputs.
+pub type Number = i128;
 
 pub struct Hailstone {
-    pub x: Number,
-    pub y: Number,
-    pub z: Number,
-    pub dx: Number,
-    pub dy: Number,
-    pub dz: Number,
+    pub pos: Vec3<Number>,
+    pub vel: Vec3<Number>,
 }
 
 fn solve(input: &AsciiStr) -> Result<()> {
@@ -34,7 +35,7 @@ fn solve(input: &AsciiStr) -> Result<()> {
     let mut hailstones = Vec::with_capacity(300);
 
     for line in input.lines() {
-        let [[x, y, z], [dx, dy, dz]]: [[Number; 3]; 2] = line
+        let [pos, vel]: [[Number; 3]; 2] = line
             .split_exact(AsciiChar::At)?
             .map(|coords| {
                 coords
@@ -45,74 +46,49 @@ fn solve(input: &AsciiStr) -> Result<()> {
             })
             .factor()?;
         hailstones.push(Hailstone {
-            x,
-            y,
-            z,
-            dx,
-            dy,
-            dz,
+            pos: pos.into(),
+            vel: vel.into(),
         });
     }
 
-    let mut x = ndarray::Array1::<f64>::zeros((6,));
-    let mut y = ndarray::Array1::<f64>::zeros((3 * hailstones.len(),));
-    let mut j = ndarray::Array2::<f64>::zeros((3 * hailstones.len(), 6));
-    loop {
-        for ((h, mut j), mut y) in hailstones
-            .iter()
-            .zip(j.axis_chunks_iter_mut(ndarray::Axis(0), 3))
-            .zip(y.axis_chunks_iter_mut(ndarray::Axis(0), 3))
-        {
-            let xdiff = x[0] - h.x;
-            let ydiff = x[1] - h.y;
-            let zdiff = x[2] - h.z;
-            let dxdiff = x[3] - h.dx;
-            let dydiff = x[4] - h.dy;
-            let dzdiff = x[5] - h.dz;
-
-            j[(0, 0)] = dydiff;
-            j[(0, 1)] = -dxdiff;
-            j[(0, 2)] = 0.0;
-            j[(0, 3)] = -ydiff;
-            j[(0, 4)] = xdiff;
-            j[(0, 5)] = 0.0;
-
-            j[(1, 0)] = dzdiff;
-            j[(1, 1)] = 0.0;
-            j[(1, 2)] = -dxdiff;
-            j[(1, 3)] = -zdiff;
-            j[(1, 4)] = 0.0;
-            j[(1, 5)] = xdiff;
-
-            j[(2, 0)] = 0.0;
-            j[(2, 1)] = dzdiff;
-            j[(2, 2)] = -dydiff;
-            j[(2, 3)] = 0.0;
-            j[(2, 4)] = -zdiff;
-            j[(2, 5)] = ydiff;
-
-            y[0] = xdiff * dydiff - ydiff * dxdiff;
-            y[1] = xdiff * dzdiff - zdiff * dxdiff;
-            y[2] = ydiff * dzdiff - zdiff * dydiff;
-        }
-
-        let a = j.least_squares_in_place(&mut y)?;
-        x -= &a.solution;
-        if let Some(res) = a.residual_sum_of_squares {
-            if res[()] < 1e-20 {
-                break;
-            }
+    // The rock (p, v) meets every hailstone: (p - pᵢ) × (v - vᵢ) = 0.
+    // Subtracting the equations of two hailstones cancels the quadratic term p × v:
+    // p × (vⱼ - vᵢ) + (pⱼ - pᵢ) × v = pⱼ × vⱼ - pᵢ × vᵢ
+    let big = |n: Number| Rational::from_integer(BigInt::from(n));
+    let mut a = Matrix::new();
+    let mut b = Vec::new();
+    let h0 = hailstones.first().ok_or(anyhow!("No hailstone"))?;
+    for h in &hailstones[1..] {
+        let Vec3 {
+            x: dx,
+            y: dy,
+            z: dz,
+        } = h.vel - h0.vel;
+        let Vec3 {
+            x: ex,
+            y: ey,
+            z: ez,
+        } = h.pos - h0.pos;
+        for row in [
+            [0, dz, -dy, 0, -ez, ey],
+            [-dz, 0, dx, ez, 0, -ex],
+            [dy, -dx, 0, -ey, ex, 0],
+        ] {
+            a.push(row.map(big).to_vec());
         }
+        let rhs = h.pos.cross(h.vel) - h0.pos.cross(h0.vel);
+        b.extend([rhs.x, rhs.y, rhs.z].map(big));
     }
-
-    let z = x[2].round() as i64;
-    let y = x[1].round() as i64;
-    let x = x[0].round() as i64;
+    let rock = least_squares(&a, &b)?;
+    if let Some(c) = rock[..3].iter().find(|c| !c.is_integer()) {
+        bail!("The rock starts at a non-integer coordinate {c}");
+    }
+    let [x, y, z] = [&rock[0], &rock[1], &rock[2]].map(|c| c.to_integer());
 
     let elapsed = timer.elapsed();
     println!(
         "Part B ({elapsed:?}):\nx: {x}\ty: {y}\tz: {z}\n{}",
-        x + y + z
+        &x + &y + &z
     );
     Ok(())
 }
@@ -1,9 +1,9 @@
 use std::time::Instant;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use aoc_lib::{
-    ascii::{AsAsciiStr, AsciiChar, AsciiStr},
-    SplitExact, SplitWhitespace,
+    ascii::{AsAsciiStr, AsciiStr},
+    graph::{stoer_wagner, GraphBuilder},
 };
 
 fn main() {
@@ -18,16 +18,15 @@ fn main() {
 fn solve(input: &AsciiStr) -> Result<()> {
     let timer = Instant::now();
 
-    println!("strict graph {{");
-    for line in input.lines() {
-        let [node, neighbors] = line.split_exact(AsciiChar::Colon)?;
-        for neighbor in neighbors.trim().split_whitespace() {
-            println!("  {node} -- {neighbor}");
-        }
-    }
-    println!("}}");
+    let mut builder = GraphBuilder::<()>::undirected();
+    builder.lines(input)?;
+    let graph = builder.build();
+
+    let cut = stoer_wagner(&graph, |_| 1).ok_or(anyhow!("Graph is too small to be cut"))?;
+    let [a, b] = &cut.partition;
+    let n = a.len() * b.len();
 
     let elapsed = timer.elapsed();
-    println!("Part A ({elapsed:?}):\n{input}");
+    println!("Part A ({elapsed:?}):\n{n}");
     Ok(())
 }